@@ -1,63 +0,0 @@
-use aoc::Parse;
-
-type Input = u64;
-type Output = usize;
-
-register!(
-    "input/day.txt";
-    (input: input!(parse Input)) -> Output {
-        part1(&input);
-        part2(&input);
-    }
-);
-
-fn part1(items: &[Input]) -> Output {
-    0
-}
-
-fn part2(items: &[Input]) -> Output {
-    0
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 0);
-        assert_eq!(res2, 0);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 0);
-        assert_eq!(res2, 0);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = `input.len()' as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
@@ -0,0 +1,180 @@
+//! Allocation accounting with the `count-allocs` feature.
+//!
+//! The feature installs [`CountingAllocator`] as the global allocator. It counts per thread,
+//! so that days that are solved in parallel do not see each other's allocations.
+//! Without the feature, [`Counter`] does nothing and all [`Allocs`] are zero.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt,
+};
+
+/// Whether allocations are counted at all.
+pub const ENABLED: bool = cfg!(feature = "count-allocs");
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+thread_local! {
+    static COUNT: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+    static CURRENT: Cell<i64> = const { Cell::new(0) };
+    static PEAK: Cell<i64> = const { Cell::new(0) };
+}
+
+/// The allocations of one phase of a day.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Allocs {
+    /// Number of allocations, including reallocations.
+    pub count: u64,
+    /// Total size of all allocations.
+    pub bytes: u64,
+    /// Largest amount of heap that was in use at once, above the heap at the start of the phase.
+    pub peak: u64,
+}
+
+impl fmt::Display for Allocs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {}, peak {}",
+            self.count,
+            Bytes(self.bytes),
+            Bytes(self.peak)
+        )
+    }
+}
+
+/// Counts the allocations of the current thread between [`Counter::start`] and [`Counter::stop`].
+#[must_use]
+pub struct Counter {
+    count: u64,
+    bytes: u64,
+    current: i64,
+}
+
+impl Counter {
+    pub fn start() -> Self {
+        let current = CURRENT.with(Cell::get);
+        PEAK.with(|peak| peak.set(current));
+
+        Self {
+            count: COUNT.with(Cell::get),
+            bytes: BYTES.with(Cell::get),
+            current,
+        }
+    }
+
+    pub fn stop(self) -> Allocs {
+        if !ENABLED {
+            return Allocs::default();
+        }
+
+        Allocs {
+            count: COUNT.with(Cell::get) - self.count,
+            bytes: BYTES.with(Cell::get) - self.bytes,
+            peak: u64::try_from(PEAK.with(Cell::get) - self.current).unwrap_or(0),
+        }
+    }
+}
+
+/// A [`System`] allocator that keeps track of the allocations of each thread.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record(allocated: usize, freed: usize) {
+        // The counters are plain `Cell`s without destructors, so they can always be accessed,
+        // but ignore the counts of a thread that is shutting down, just to be sure.
+        let _ = CURRENT.try_with(|current| {
+            let now = current.get() + allocated as i64 - freed as i64;
+            current.set(now);
+            PEAK.with(|peak| peak.set(peak.get().max(now)));
+        });
+        if allocated > 0 {
+            let _ = COUNT.try_with(|count| count.set(count.get() + 1));
+            let _ = BYTES.try_with(|bytes| bytes.set(bytes.get() + allocated as u64));
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// A number of bytes in the largest fitting binary unit.
+pub struct Bytes(pub u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{value:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(Bytes(0).to_string(), "0 B");
+        assert_eq!(Bytes(1023).to_string(), "1023 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 << 20).to_string(), "3.0 MiB");
+    }
+
+    #[test]
+    fn test_counter() {
+        let counter = Counter::start();
+        let data = std::hint::black_box(vec![0_u8; 4096]);
+        drop(data);
+        let allocs = counter.stop();
+
+        if ENABLED {
+            assert_eq!(allocs.count, 1);
+            assert_eq!(allocs.bytes, 4096);
+            assert_eq!(allocs.peak, 4096);
+        } else {
+            assert_eq!(allocs, Allocs::default());
+        }
+    }
+}
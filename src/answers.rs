@@ -0,0 +1,173 @@
+//! Known answers, stored per year in `answers/<year>.toml`.
+//!
+//! ```toml
+//! [day1]
+//! part1 = 70116
+//! part2 = "206582"
+//! ```
+//!
+//! Multi-line answers, like letters drawn on a screen, use multi-line strings.
+//! Surrounding whitespace is ignored when comparing answers.
+
+use std::{
+    collections::HashMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+/// Directory with the answer files, relative to the crate root.
+pub const DEFAULT_DIR: &str = "answers";
+
+/// The result of comparing an answer with the known answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    #[must_use]
+    pub const fn is_wrong(&self) -> bool {
+        matches!(self, Self::Wrong { .. })
+    }
+}
+
+/// The known answers of one year.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: HashMap<u8, [Option<String>; 2]>,
+}
+
+impl Answers {
+    /// The default location of the answers of `year`.
+    #[must_use]
+    pub fn default_path(year: u16) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join(DEFAULT_DIR)
+            .join(format!("{year}.toml"))
+    }
+
+    /// Loads the answers of `year` from `dir`, a missing file means that no answers are known.
+    ///
+    /// # Errors
+    /// When the file cannot be read or is invalid.
+    pub fn load(dir: Option<&Path>, year: u16) -> Result<Self, String> {
+        let path = dir.map_or_else(
+            || Self::default_path(year),
+            |dir| dir.join(format!("{year}.toml")),
+        );
+
+        match std::fs::read_to_string(&path) {
+            Ok(content) => Self::parse(&content)
+                .map_err(|e| format!("Invalid answers in {}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Could not read answers {}: {e}", path.display())),
+        }
+    }
+
+    /// # Errors
+    /// When `content` is not valid TOML or does not contain the expected tables.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let table = content.parse::<toml::Value>().map_err(|e| e.to_string())?;
+        let table = table.as_table().ok_or("Expected a table")?;
+
+        let mut days = HashMap::new();
+        for (key, value) in table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| format!("Invalid day `{key}`, expected e.g. `day1`"))?;
+            days.insert(day, parse_parts(key, value)?);
+        }
+
+        Ok(Self { days })
+    }
+
+    #[must_use]
+    pub fn expected(&self, day: u8, part: u8) -> Option<&str> {
+        let index = usize::from(part.checked_sub(1)?);
+        self.days.get(&day)?.get(index)?.as_deref()
+    }
+
+    #[must_use]
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        check(self.expected(day, part), answer)
+    }
+}
+
+/// Reads a table with the optional answers `part1` and `part2`, `key` is its name.
+pub(crate) fn parse_parts(key: &str, value: &toml::Value) -> Result<[Option<String>; 2], String> {
+    let value = value
+        .as_table()
+        .ok_or_else(|| format!("Expected `{key}` to be a table"))?;
+
+    let mut answers = [None, None];
+    for (part, value) in value {
+        let index = match part.as_str() {
+            "part1" => 0,
+            "part2" => 1,
+            _ => {
+                return Err(format!(
+                    "Invalid part `{key}.{part}`, expected part1 or part2"
+                ))
+            }
+        };
+        answers[index] = Some(match value {
+            toml::Value::String(s) => s.clone(),
+            toml::Value::Integer(i) => i.to_string(),
+            _ => return Err(format!("Expected `{key}.{part}` to be a string or integer")),
+        });
+    }
+    Ok(answers)
+}
+
+pub(crate) fn check(expected: Option<&str>, answer: &str) -> Verdict {
+    match expected {
+        Some(expected) if expected.trim() == answer.trim() => Verdict::Correct,
+        Some(expected) => Verdict::Wrong {
+            expected: expected.trim().to_owned(),
+        },
+        None => Verdict::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[day1]
+part1 = 70116
+part2 = "206582"
+
+[day10]
+part1 = 16060
+part2 = '''
+###..
+#..#.
+'''
+"#;
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check(1, 1, "70116"), Verdict::Correct);
+        assert_eq!(
+            answers.check(1, 2, "1337"),
+            Verdict::Wrong {
+                expected: String::from("206582")
+            }
+        );
+        assert_eq!(answers.check(10, 2, "###..\n#..#.\n"), Verdict::Correct);
+        assert_eq!(answers.check(2, 1, "15"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(Answers::parse("[one]\npart1 = 1").is_err());
+        assert!(Answers::parse("[day1]\npart3 = 1").is_err());
+        assert!(Answers::parse("[day1]\npart1 = 1.5").is_err());
+        assert!(Answers::parse("day1 = 1").is_err());
+    }
+}
@@ -0,0 +1,460 @@
+use std::{
+    fmt,
+    hint::black_box,
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    alloc::{self, Allocs},
+    report::Format,
+    runner::Day,
+    Parts, PuzzleInput, PuzzleSolution, Solution,
+};
+
+/// Time spent running a day before measuring, to warm up caches and estimate its run time.
+const WARMUP_TIME: Duration = Duration::from_millis(500);
+/// Time that the measurement of a single day should take.
+const MEASUREMENT_TIME: Duration = Duration::from_secs(2);
+/// Minimum time of a single sample. Faster days run several times per sample and the
+/// phases are averaged, so that the resolution and overhead of the timer do not dominate.
+const MIN_SAMPLE_TIME: Duration = Duration::from_millis(1);
+const MIN_SAMPLES: usize = 5;
+const MAX_SAMPLES: usize = 100_000;
+
+const CSV_HEADER: &str =
+    "year,day,phase,samples,median_ns,deviation_ns,outliers,mib_per_second,allocs,alloc_bytes,peak_bytes";
+
+/// Runs the full day once, with the infallible parsing path that the puzzles normally use.
+/// The answers are discarded, only the time of each phase is kept.
+pub(crate) fn sample<S: Solution>(input: &str) -> PuzzleSolution<()> {
+    let counter = alloc::Counter::start();
+    let start = Instant::now();
+    let parsed = black_box(<S::Input as PuzzleInput>::from_input(black_box(input)));
+    let parse_time = start.elapsed();
+    let parse_allocs = counter.stop();
+
+    let solution = S::run_parts(parsed, parse_time, Parts::BOTH).map(|answer| {
+        black_box(answer);
+    });
+    PuzzleSolution {
+        parse_allocs,
+        ..solution
+    }
+}
+
+/// The three phases of a day, as measured by [`Day::sample`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::Part1 => "part1",
+            Self::Part2 => "part2",
+        }
+    }
+
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Parse => "Parsing",
+            Self::Part1 => "Part 1",
+            Self::Part2 => "Part 2",
+        }
+    }
+}
+
+/// Summary statistics over the samples of one phase.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub median: Duration,
+    /// Median absolute deviation, scaled to be comparable to a standard deviation.
+    pub deviation: Duration,
+    /// Samples outside of 1.5 times the interquartile range.
+    pub outliers: usize,
+    /// Only known for parsing, which handles the whole input.
+    pub mib_per_second: Option<f64>,
+    /// The allocations of a single run, if they are counted.
+    pub allocs: Option<Allocs>,
+}
+
+impl Stats {
+    /// `bytes` is the size of the data that was processed in each sample, if any.
+    ///
+    /// # Panics
+    /// When `samples` is empty.
+    #[must_use]
+    pub fn new(samples: &[Duration], bytes: Option<usize>) -> Self {
+        assert!(!samples.is_empty(), "No samples to summarize");
+
+        let mut nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        nanos.sort_by(f64::total_cmp);
+
+        let median = percentile(&nanos, 0.5);
+        let (q1, q3) = (percentile(&nanos, 0.25), percentile(&nanos, 0.75));
+        let (low, high) = (q1 - 1.5 * (q3 - q1), q3 + 1.5 * (q3 - q1));
+        let outliers = nanos.iter().filter(|&&n| n < low || n > high).count();
+
+        let mut deviations = nanos.iter().map(|n| (n - median).abs()).collect::<Vec<_>>();
+        deviations.sort_by(f64::total_cmp);
+        let deviation = percentile(&deviations, 0.5) * 1.4826;
+
+        let mib_per_second = bytes
+            .filter(|_| median > 0.0)
+            .map(|bytes| bytes as f64 * 1e9 / median / f64::from(1 << 20));
+
+        Self {
+            samples: samples.len(),
+            median: Duration::from_nanos(median.round() as u64),
+            deviation: Duration::from_nanos(deviation.round() as u64),
+            outliers,
+            mib_per_second,
+            allocs: None,
+        }
+    }
+}
+
+/// Linear interpolation between the closest ranks of the `sorted` values.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - rank.floor())
+}
+
+/// The statistics of all three phases of one day.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Measurement {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Measurement {
+    #[must_use]
+    pub const fn phase(&self, phase: Phase) -> &Stats {
+        match phase {
+            Phase::Parse => &self.parse,
+            Phase::Part1 => &self.part1,
+            Phase::Part2 => &self.part2,
+        }
+    }
+}
+
+/// Benchmarks a day on `input`.
+///
+/// The day is first run for [`WARMUP_TIME`], which also estimates how many samples
+/// fit into [`MEASUREMENT_TIME`]. Every sample runs all phases, since part 2 can
+/// depend on the state that part 1 leaves behind, see [`batch`].
+#[must_use]
+pub fn measure(day: &Day, input: &str) -> Measurement {
+    let start = Instant::now();
+    let mut warmup_runs = 0_u32;
+    while warmup_runs == 0 || start.elapsed() < WARMUP_TIME {
+        black_box(day.sample(input));
+        warmup_runs += 1;
+    }
+    let per_sample = (start.elapsed() / warmup_runs).max(MIN_SAMPLE_TIME);
+
+    let samples = (MEASUREMENT_TIME.as_nanos() / per_sample.as_nanos())
+        .clamp(MIN_SAMPLES as u128, MAX_SAMPLES as u128) as usize;
+    let samples = (0..samples).map(|_| batch(day, input)).collect::<Vec<_>>();
+
+    let stats = |time: fn(&PuzzleSolution<()>) -> Duration, allocs: Allocs, bytes| Stats {
+        allocs: alloc::ENABLED.then_some(allocs),
+        ..Stats::new(&samples.iter().map(time).collect::<Vec<_>>(), bytes)
+    };
+    // Allocations hardly vary between runs, so the last run stands in for all of them.
+    let last = &samples[samples.len() - 1];
+
+    Measurement {
+        parse: stats(|s| s.parse_time, last.parse_allocs, Some(input.len())),
+        part1: stats(|s| s.part1_time, last.part1_allocs, None),
+        part2: stats(|s| s.part2_time, last.part2_allocs, None),
+    }
+}
+
+/// One sample of a day: runs it until [`MIN_SAMPLE_TIME`] has passed, and returns the
+/// time of each phase divided by the number of runs, with the allocations of the last run.
+fn batch(day: &Day, input: &str) -> PuzzleSolution<()> {
+    let start = Instant::now();
+    let mut runs = 0_u32;
+    let mut total = PuzzleSolution::default();
+    while runs == 0 || start.elapsed() < MIN_SAMPLE_TIME {
+        let run = day.sample(input);
+        total = PuzzleSolution {
+            parse_time: total.parse_time + run.parse_time,
+            part1_time: total.part1_time + run.part1_time,
+            part2_time: total.part2_time + run.part2_time,
+            ..run
+        };
+        runs += 1;
+    }
+
+    PuzzleSolution {
+        parse_time: total.parse_time / runs,
+        part1_time: total.part1_time / runs,
+        part2_time: total.part2_time / runs,
+        ..total
+    }
+}
+
+/// Writes benchmark results in one [`Format`].
+pub struct BenchReport<W> {
+    out: W,
+    format: Format,
+}
+
+impl<W: Write> BenchReport<W> {
+    pub fn new(out: W, format: Format) -> Self {
+        Self { out, format }
+    }
+
+    /// # Errors
+    /// When writing fails.
+    pub fn begin(&mut self) -> io::Result<()> {
+        match self.format {
+            Format::Csv => writeln!(self.out, "{CSV_HEADER}"),
+            Format::Text | Format::Json => Ok(()),
+        }
+    }
+
+    /// Writes parsing and the selected `parts` of a day.
+    ///
+    /// # Errors
+    /// When writing fails.
+    pub fn day(
+        &mut self,
+        year: u16,
+        day: u8,
+        parts: Parts,
+        measurement: &Measurement,
+    ) -> io::Result<()> {
+        let phases = [
+            Some(Phase::Parse),
+            parts.part1.then_some(Phase::Part1),
+            parts.part2.then_some(Phase::Part2),
+        ];
+        let phases = phases.into_iter().flatten();
+
+        if self.format == Format::Text {
+            writeln!(self.out, "Year {year}, day {day:02}")?;
+        }
+        for phase in phases {
+            let stats = measurement.phase(phase);
+            match self.format {
+                Format::Text => writeln!(self.out, "  - {}", TextRecord(phase, stats))?,
+                Format::Json => writeln!(self.out, "{}", JsonRecord(year, day, phase, stats))?,
+                Format::Csv => writeln!(self.out, "{}", CsvRecord(year, day, phase, stats))?,
+            }
+        }
+        if self.format == Format::Text {
+            writeln!(self.out)?;
+        }
+
+        Ok(())
+    }
+}
+
+struct TextRecord<'a>(Phase, &'a Stats);
+
+impl fmt::Display for TextRecord<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(phase, stats) = self;
+        write!(
+            f,
+            "{:<8} {:>10} ± {:<10}",
+            phase.label(),
+            Nanos(stats.median),
+            Nanos(stats.deviation)
+        )?;
+        match stats.mib_per_second {
+            Some(mib_per_second) => write!(f, " {mib_per_second:>9.2} MiB/s")?,
+            None => write!(f, " {:>15}", "")?,
+        }
+        write!(
+            f,
+            " ({} samples, {} outliers)",
+            stats.samples, stats.outliers
+        )?;
+        if let Some(allocs) = stats.allocs {
+            write!(f, " ({allocs})")?;
+        }
+        Ok(())
+    }
+}
+
+struct JsonRecord<'a>(u16, u8, Phase, &'a Stats);
+
+impl fmt::Display for JsonRecord<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(year, day, phase, stats) = self;
+        write!(
+            f,
+            r#"{{"year":{year},"day":{day},"phase":"{}","samples":{},"median_ns":{},"deviation_ns":{},"outliers":{},"mib_per_second":"#,
+            phase.name(),
+            stats.samples,
+            stats.median.as_nanos(),
+            stats.deviation.as_nanos(),
+            stats.outliers,
+        )?;
+        match stats.mib_per_second {
+            Some(mib_per_second) => write!(f, "{mib_per_second:.2}")?,
+            None => f.write_str("null")?,
+        }
+        match stats.allocs {
+            Some(allocs) => write!(
+                f,
+                r#","allocs":{},"alloc_bytes":{},"peak_bytes":{}}}"#,
+                allocs.count, allocs.bytes, allocs.peak
+            ),
+            None => f.write_str(r#","allocs":null,"alloc_bytes":null,"peak_bytes":null}"#),
+        }
+    }
+}
+
+struct CsvRecord<'a>(u16, u8, Phase, &'a Stats);
+
+impl fmt::Display for CsvRecord<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(year, day, phase, stats) = self;
+        write!(
+            f,
+            "{year},{day},{},{},{},{},{},",
+            phase.name(),
+            stats.samples,
+            stats.median.as_nanos(),
+            stats.deviation.as_nanos(),
+            stats.outliers,
+        )?;
+        if let Some(mib_per_second) = stats.mib_per_second {
+            write!(f, "{mib_per_second:.2}")?;
+        }
+        match stats.allocs {
+            Some(allocs) => write!(f, ",{},{},{}", allocs.count, allocs.bytes, allocs.peak),
+            None => f.write_str(",,,"),
+        }
+    }
+}
+
+/// A duration with three significant decimals in the largest fitting unit.
+struct Nanos(Duration);
+
+impl fmt::Display for Nanos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+        let (value, unit) = match nanos {
+            n if n >= 1e9 => (n / 1e9, "s"),
+            n if n >= 1e6 => (n / 1e6, "ms"),
+            n if n >= 1e3 => (n / 1e3, "µs"),
+            n => (n, "ns"),
+        };
+        f.pad(&format!("{value:.3} {unit}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    fn nanos(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    static RUNS: AtomicU32 = AtomicU32::new(0);
+
+    /// A day whose part 1 claims to take one more microsecond on every run.
+    struct Slower;
+
+    impl Solution for Slower {
+        type Input = ();
+        type Output = u32;
+
+        fn try_puzzle_input() -> io::Result<&'static str> {
+            Ok("")
+        }
+
+        fn run_parts(
+            _input: (),
+            parse_time: Duration,
+            parts: Parts,
+        ) -> PuzzleSolution<Option<u32>> {
+            let run = RUNS.fetch_add(1, Ordering::Relaxed) + 1;
+            PuzzleSolution {
+                part1: parts.part1.then_some(run),
+                part2: parts.part2.then_some(run),
+                parse_time,
+                part1_time: Duration::from_micros(run.into()),
+                ..PuzzleSolution::default()
+            }
+        }
+    }
+
+    #[test]
+    fn test_batch() {
+        let sample = batch(&Day::new::<Slower>(1), "");
+        let runs = RUNS.load(Ordering::Relaxed);
+        assert!(runs > 1, "a fast day runs several times per sample");
+        // The average of 1, 2, ..., `runs` microseconds.
+        assert_eq!(
+            sample.part1_time,
+            Duration::from_nanos(500 * u64::from(runs + 1))
+        );
+        assert_eq!(sample.part2_time, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&nanos(&[12, 10, 11, 13, 10, 11, 90]), None);
+        assert_eq!(stats.samples, 7);
+        assert_eq!(stats.median, Duration::from_nanos(11));
+        assert_eq!(stats.deviation, Duration::from_nanos(1));
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.mib_per_second, None);
+    }
+
+    #[test]
+    fn test_stats_even_samples() {
+        let stats = Stats::new(&nanos(&[40, 10, 20, 30]), None);
+        assert_eq!(stats.median, Duration::from_nanos(25));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn test_throughput() {
+        let stats = Stats::new(&nanos(&[1_000_000]), Some(1 << 20));
+        assert_eq!(stats.mib_per_second, Some(1000.0));
+    }
+
+    #[test]
+    fn test_records() {
+        let stats = Stats::new(&nanos(&[1500]), Some(3 << 20));
+        assert_eq!(
+            JsonRecord(2022, 1, Phase::Parse, &stats).to_string(),
+            r#"{"year":2022,"day":1,"phase":"parse","samples":1,"median_ns":1500,"deviation_ns":0,"outliers":0,"mib_per_second":2000000.00,"allocs":null,"alloc_bytes":null,"peak_bytes":null}"#
+        );
+        let stats = Stats {
+            allocs: Some(Allocs {
+                count: 2,
+                bytes: 96,
+                peak: 64,
+            }),
+            ..Stats::new(&nanos(&[2_500_000]), None)
+        };
+        assert_eq!(
+            CsvRecord(2022, 1, Phase::Part2, &stats).to_string(),
+            "2022,1,part2,1,2500000,0,0,,2,96,64"
+        );
+        assert_eq!(Nanos(stats.median).to_string(), "2.500 ms");
+    }
+}
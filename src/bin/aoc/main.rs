@@ -0,0 +1,36 @@
+#![cfg_attr(feature = "nightly", feature(portable_simd))]
+#![cfg_attr(all(test, feature = "nightly"), feature(test))]
+#![warn(
+    clippy::all,
+    clippy::pedantic,
+    clippy::nursery,
+    clippy::cargo,
+    rust_2018_idioms
+)]
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss,
+    clippy::fallible_impl_from,
+    clippy::iter_with_drain,
+    clippy::missing_const_for_fn,
+    clippy::multiple_crate_versions,
+    clippy::redundant_pub_crate,
+    clippy::similar_names,
+    elided_lifetimes_in_paths
+)]
+
+#[macro_use]
+extern crate aoc;
+#[cfg(all(test, feature = "nightly"))]
+extern crate test;
+
+#[path = "../aoc2020/mod.rs"]
+mod aoc2020;
+#[path = "../aoc2021/mod.rs"]
+mod aoc2021;
+#[path = "../aoc2022/mod.rs"]
+mod aoc2022;
+
+aoc_main!(aoc2020, aoc2021, aoc2022);
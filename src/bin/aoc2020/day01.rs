@@ -7,6 +7,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 805_731, 192_684_960;
 );
 
 fn part1(input: &[Input]) -> Output {
@@ -26,7 +27,8 @@ fn part1(input: &[Input]) -> Output {
 fn part2(mut input: &[Input]) -> Output {
     let mut seen = [false; 2021];
 
-    while let Some(&first) = input.take_first() {
+    while let Some((&first, rest)) = input.split_first() {
+        input = rest;
         let target = 2020 - first;
 
         for &second in input {
@@ -46,48 +48,6 @@ fn part2(mut input: &[Input]) -> Output {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test_ex() {
-        let (res1, res2) = Solver::run_on(
-            r#"
-        1721
-        979
-        366
-        299
-        675
-        1456
-        "#,
-        );
-        assert_eq!(res1, 514_579);
-        assert_eq!(res2, 241_861_950);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 805_731);
-        assert_eq!(res2, 192_684_960);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day1"; ex1);
 }
@@ -6,6 +6,7 @@ register!(
         part1(input);
         part2(input);
     }
+    answers: 418, 616;
 );
 
 fn part1((pt1, _): (usize, usize)) -> usize {
@@ -42,7 +43,7 @@ impl PasswordInput {
         let max = max.parse::<usize>().unwrap();
         let (letter, input) = input.split_once(':').unwrap();
         let letter = letter.as_bytes()[0];
-        let pass = input[1..].as_bytes();
+        let pass = &input.as_bytes()[1..];
 
         #[allow(clippy::naive_bytecount)]
         let pt1 = pass.iter().filter(|b| **b == letter).count();
@@ -52,36 +53,3 @@ impl PasswordInput {
         Self { pt1, pt2 }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 418);
-        assert_eq!(res2, 616);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(input));
-    }
-}
@@ -8,6 +8,7 @@ register!(
         max_seat_id(input.iter());
         find_seat(&input);
     }
+    answers: 848, 682;
 );
 
 #[derive(Copy, Clone, Debug, Default, Ord, PartialOrd, Eq, PartialEq)]
@@ -67,8 +68,6 @@ fn find_seat(seats: &[Seat]) -> u16 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
     #[test]
     fn test_parse_seat() {
@@ -77,30 +76,4 @@ mod tests {
         assert_eq!(119, Seat::parse_from("FFFBBBFRRR").0);
         assert_eq!(820, Seat::parse_from("BBFFBBFRLL").0);
     }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 848);
-        assert_eq!(res2, 682);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| max_seat_id(input.iter()));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| find_seat(&input));
-    }
 }
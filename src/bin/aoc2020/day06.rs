@@ -8,6 +8,7 @@ register!(
         run(&input, BitOrAssign::bitor_assign);
         run(&input, BitAndAssign::bitand_assign);
     }
+    answers: 7110, 3628;
 );
 
 #[derive(Copy, Clone, Debug, Default)]
@@ -67,57 +68,6 @@ fn run(input: &[Vec<Answers>], op: impl Fn(&mut Answers, Answers)) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 7110);
-        assert_eq!(res2, 3628);
-    }
-
-    #[test]
-    fn test_ex1() {
-        let input = "
-
-            abc
-
-            a
-            b
-            c
-
-            ab
-            ac
-
-
-            a
-            a
-            a
-            a
-
-            b
-
-";
-        assert_eq!((11, 6), Solver::run_on(input));
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run(&input, BitOrAssign::bitor_assign));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run(&input, BitAndAssign::bitand_assign));
-    }
+    examples!("examples/day6"; ex1);
 }
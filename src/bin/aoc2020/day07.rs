@@ -7,6 +7,7 @@ register!(
         run1(&input);
         run2(&input);
     }
+    answers: 222, 13264;
 );
 
 pub type Rules<'a> = HashMap<&'a str, Vec<Bag<'a>>>;
@@ -15,8 +16,7 @@ fn run1(input: &Rules) -> usize {
     let mut total = 0;
     for key in input.keys() {
         let mut keys = vec![key];
-        while !keys.is_empty() {
-            let key = keys.pop().unwrap();
+        while let Some(key) = keys.pop() {
             for Bag { color, .. } in &input[key] {
                 if *color == "shiny gold" {
                     total += 1;
@@ -33,8 +33,7 @@ fn run1(input: &Rules) -> usize {
 fn run2(input: &Rules) -> usize {
     let mut total = 0;
     let mut q = vec![(1, "shiny gold")];
-    while !q.is_empty() {
-        let (mult, next) = q.pop().unwrap();
+    while let Some((mult, next)) = q.pop() {
         for Bag { amount, color } in &input[next] {
             let amt = *amount * mult;
             total += amt;
@@ -119,62 +118,6 @@ impl ProcessInput for RuleInput {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 222);
-        assert_eq!(res2, 13264);
-    }
-
-    #[test]
-    fn test_ex1() {
-        let input = "
-            light red bags contain 1 bright white bag, 2 muted yellow bags.
-            dark orange bags contain 3 bright white bags, 4 muted yellow bags.
-            bright white bags contain 1 shiny gold bag.
-            muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
-            shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
-            dark olive bags contain 3 faded blue bags, 4 dotted black bags.
-            vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
-            faded blue bags contain no other bags.
-            dotted black bags contain no other bags.
-        ";
-        assert_eq!((4, 32), Solver::run_on(input));
-    }
-
-    #[test]
-    fn test_ex2() {
-        let input = "
-            shiny gold bags contain 2 dark red bags.
-            dark red bags contain 2 dark orange bags.
-            dark orange bags contain 2 dark yellow bags.
-            dark yellow bags contain 2 dark green bags.
-            dark green bags contain 2 dark blue bags.
-            dark blue bags contain 2 dark violet bags.
-            dark violet bags contain no other bags.
-        ";
-        assert_eq!(126, Solver::run_on(input).1);
-    }
 
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run2(&input));
-    }
+    examples!("examples/day7"; ex1, ex2);
 }
@@ -7,6 +7,7 @@ register!(
         run1(&input);
         run2(&input);
     }
+    answers: 1749, 515;
 );
 
 fn run1(input: &[Op]) -> i32 {
@@ -79,52 +80,6 @@ pub enum Op {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 1749);
-        assert_eq!(res2, 515);
-    }
-
-    #[test]
-    fn test_ex1() {
-        assert_eq!(
-            (5, 8),
-            Solver::run_on(
-                "
-        nop +0
-        acc +1
-        jmp +4
-        acc +3
-        jmp -3
-        acc -99
-        acc +1
-        jmp -4
-        acc +6
-    "
-            )
-        );
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run2(&input));
-    }
+    examples!("examples/day8"; ex1);
 }
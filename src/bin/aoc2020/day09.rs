@@ -7,6 +7,7 @@ register!(
             run2(&input, part1)
         }
     }
+    answers: 1_309_761_972, 177_989_832;
 );
 
 fn run1(input: &[u64], pre_len: usize) -> u64 {
@@ -48,15 +49,7 @@ fn run2(input: &[u64], needle: u64) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 1_309_761_972);
-        assert_eq!(res2, 177_989_832);
-    }
+    use aoc::Solution;
 
     fn input() -> Vec<u64> {
         Solver::parse_input(
@@ -90,26 +83,4 @@ mod tests {
         assert_eq!(127, run1(&input(), 5));
         assert_eq!(62, run2(&input(), 127));
     }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run1(&input, 25));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| {
-            let part1 = run1(&input, 25);
-            run2(&input, part1)
-        });
-    }
 }
@@ -7,6 +7,7 @@ register!(
         run1(&input);
         run2(&input);
     }
+    answers: 2738, 74_049_191_673_856;
 );
 
 fn run1(input: &[u64]) -> u64 {
@@ -60,74 +61,6 @@ impl ProcessInput for Input {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 2738);
-        assert_eq!(res2, 74_049_191_673_856);
-    }
-
-    #[test]
-    fn test_ex1() {
-        assert_eq!(
-            (220, 19208),
-            Solver::run_on(
-                "
-        28
-        33
-        18
-        42
-        31
-        14
-        46
-        20
-        48
-        47
-        24
-        23
-        49
-        45
-        19
-        38
-        39
-        11
-        1
-        32
-        25
-        35
-        8
-        17
-        7
-        9
-        4
-        2
-        34
-        10
-        3
-    "
-            )
-        );
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run2(&input));
-    }
+    examples!("examples/day10"; ex1);
 }
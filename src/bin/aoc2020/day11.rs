@@ -4,8 +4,9 @@ register!(
     "input/day11.txt";
     (input: input!([u8])) -> usize {
         run_any(&input, 1, 4);
-        run_any(&input, usize::max_value(), 5);
+        run_any(&input, usize::MAX, 5);
     }
+    answers: 2093, 1862;
 );
 
 fn run_any(input: &[&[u8]], dist: usize, full: usize) -> usize {
@@ -110,7 +111,7 @@ impl Input for Vec<u8> {
     }
 }
 
-impl<'x> Input for &'x [u8] {
+impl Input for &[u8] {
     type Iter<'a> = std::iter::Copied<std::slice::Iter<'a, u8>>
     where
         Self: 'a;
@@ -131,36 +132,8 @@ impl<'x> Input for &'x [u8] {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 2093);
-        assert_eq!(res2, 1862);
-    }
-
-    #[test]
-    fn test_ex1() {
-        assert_eq!(
-            (37, 26),
-            Solver::run_on(
-                "
-        L.LL.LL.LL
-        LLLLLLL.LL
-        L.L.L..L..
-        LLLL.LL.LL
-        L.LL.LL.LL
-        L.LLLLL.LL
-        ..L.L.....
-        LLLLLLLLLL
-        L.LLLLLL.L
-        L.LLLLL.LL
-    "
-            )
-        );
-    }
+    examples!("examples/day11"; ex1);
 
     #[test]
     fn occupied_test1() {
@@ -180,7 +153,7 @@ mod tests {
         .map(|s| s.trim().as_bytes())
         .collect::<Vec<_>>();
 
-        assert_eq!(8, occupied_seats(3, 4, usize::max_value(), &input));
+        assert_eq!(8, occupied_seats(3, 4, usize::MAX, &input));
     }
 
     #[test]
@@ -195,7 +168,7 @@ mod tests {
         .map(|s| s.trim().as_bytes())
         .collect::<Vec<_>>();
 
-        assert_eq!(0, occupied_seats(1, 1, usize::max_value(), &input));
+        assert_eq!(0, occupied_seats(1, 1, usize::MAX, &input));
     }
 
     #[test]
@@ -214,7 +187,7 @@ mod tests {
         .map(|s| s.trim().as_bytes())
         .collect::<Vec<_>>();
 
-        assert_eq!(0, occupied_seats(3, 3, usize::max_value(), &input));
+        assert_eq!(0, occupied_seats(3, 3, usize::MAX, &input));
     }
 
     #[test]
@@ -236,25 +209,6 @@ mod tests {
         .map(|s| s.trim().as_bytes())
         .collect::<Vec<_>>();
 
-        assert_eq!(0, occupied_seats(3, 0, usize::max_value(), &input));
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run_any(&input, 1, 4));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run_any(&input, usize::max_value(), 5));
+        assert_eq!(0, occupied_seats(3, 0, usize::MAX, &input));
     }
 }
@@ -8,8 +8,9 @@ register!(
     "input/day12.txt";
     (input: input!(parse Input)) -> usize {
         Ship1::run(input.iter().copied());
-        Ship2::run(input);
+        Ship2::run(input.iter().copied());
     }
+    answers: 820, 66614;
 );
 
 trait Ship: Default + Into<Pos> {
@@ -200,48 +201,6 @@ mod dir {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 820);
-        assert_eq!(res2, 66614);
-    }
-
-    #[test]
-    fn test_ex1() {
-        assert_eq!(
-            (25, 286),
-            Solver::run_on(
-                "
-        F10
-        N3
-        F7
-        R90
-        F11
-        "
-            )
-        );
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| Ship1::run(input.iter().copied()));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| Ship2::run(input.iter().copied()));
-    }
+
+    examples!("examples/day12"; ex1);
 }
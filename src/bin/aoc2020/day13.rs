@@ -4,6 +4,7 @@ register!(
         run1(&input);
         run2(&input);
     }
+    answers: 333, 690_123_192_779_524;
 );
 
 fn run1(input: &[&str]) -> i64 {
@@ -46,28 +47,8 @@ fn run2(input: &[&str]) -> i64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 333);
-        assert_eq!(res2, 690_123_192_779_524);
-    }
-
-    #[test]
-    fn test_ex1() {
-        assert_eq!(
-            (295, 1_068_781),
-            Solver::run_on(
-                "
-        939
-        7,13,x,x,59,x,31,19
-    "
-            )
-        );
-    }
+    examples!("examples/day13"; ex1);
 
     #[test]
     fn test_p2_0() {
@@ -85,23 +66,4 @@ mod tests {
     fn test_p2_2() {
         assert_eq!(1_202_161_486, run2(["", "1789,37,47,1889"].as_ref()));
     }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run2(&input));
-    }
 }
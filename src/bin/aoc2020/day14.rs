@@ -10,6 +10,7 @@ register!(
         run1(&input);
         run2(&input);
     }
+    answers: 8_332_632_930_672, 4_753_238_784_664;
 );
 
 #[derive(Debug, Default)]
@@ -43,7 +44,7 @@ fn run1(input: &[Input]) -> Output {
                     let value = (value & s.keep_mask) | s.set_mask;
                     s.memory.insert(*addr, value);
                 }
-            };
+            }
             s
         })
         .memory
@@ -69,7 +70,7 @@ fn run2(input: &[Input]) -> Output {
                         .rev()
                         .enumerate()
                         .filter_map(|(i, b)| if b == b'X' { Some(i) } else { None })
-                        .fold(vec![u64::max_value()], |ms, i| {
+                        .fold(vec![u64::MAX], |ms, i| {
                             ms.into_iter()
                                 .flat_map(|m| vec![m & !(1 << i), m | (1 << i)])
                                 .collect()
@@ -88,7 +89,7 @@ fn run2(input: &[Input]) -> Output {
                         s.memory.insert(addr, *value);
                     }
                 }
-            };
+            }
             s
         })
         .memory
@@ -107,15 +108,7 @@ pub enum Op {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 8_332_632_930_672);
-        assert_eq!(res2, 4_753_238_784_664);
-    }
+    use aoc::Solution;
 
     #[test]
     fn test_pt1() {
@@ -138,23 +131,4 @@ mod tests {
         ";
         assert_eq!(208, run2(&Solver::parse_input(input)));
     }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run2(&input));
-    }
 }
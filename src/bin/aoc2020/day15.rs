@@ -6,6 +6,7 @@ register!(
         run1(&input);
         run2(&input);
     }
+    answers: 1238, 3_745_954;
 );
 
 fn run1(input: &[&str]) -> Output {
@@ -43,37 +44,9 @@ fn run_any(input: &[&str], stop_at: Output) -> Output {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 1238);
-        assert_eq!(res2, 3_745_954);
-    }
 
     #[test]
     fn test_pt1() {
         assert_eq!(436, run1(["0,3,6"].as_ref()));
     }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run2(&input));
-    }
 }
@@ -1,5 +1,6 @@
+use aoc::interval::IntervalSet;
 use parse_display::FromStr;
-use std::{collections::HashSet, ops::RangeInclusive};
+use std::collections::HashSet;
 
 type Output = usize;
 
@@ -9,18 +10,18 @@ register!(
         run1(&input);
         run2(&input);
     }
+    answers: 18227, 2_355_350_878_831;
 );
 
 #[derive(Debug, Clone)]
 struct Rule {
     departure: bool,
-    r1: RangeInclusive<Output>,
-    r2: RangeInclusive<Output>,
+    valid: IntervalSet<Output>,
 }
 
 impl Rule {
     fn valid(&self, num: Output) -> bool {
-        self.r1.contains(&num) || self.r2.contains(&num)
+        self.valid.contains(num)
     }
 }
 
@@ -30,9 +31,8 @@ impl From<RuleAlternative> for Rule {
             RuleAlternative::Departure(range) => (true, range),
             RuleAlternative::Other(range) => (false, range),
         };
-        let r1 = a.min..=a.max;
-        let r2 = b.min..=b.max;
-        Self { departure, r1, r2 }
+        let valid = [a.min..=a.max, b.min..=b.max].into_iter().collect();
+        Self { departure, valid }
     }
 }
 
@@ -64,11 +64,11 @@ fn run1(input: &[Vec<&str>]) -> Output {
     let _my_tickets = chunks.next().unwrap();
     let other_tickets = chunks.next().unwrap();
 
-    let rules = rules
+    let valid = rules
         .iter()
         .map(|s| s.parse::<RuleAlternative>().unwrap())
         .map(Rule::from)
-        .collect::<Vec<_>>();
+        .fold(IntervalSet::new(), |valid, rule| valid.union(&rule.valid));
 
     other_tickets
         .iter()
@@ -77,7 +77,7 @@ fn run1(input: &[Vec<&str>]) -> Output {
             t.split(',')
                 .map(str::parse::<Output>)
                 .map(Result::unwrap)
-                .filter(|n| !rules.iter().any(|r| r.valid(*n)))
+                .filter(|&n| !valid.contains(n))
                 .sum::<Output>()
         })
         .sum()
@@ -154,15 +154,7 @@ fn run2(input: &[Vec<&str>]) -> Output {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 18227);
-        assert_eq!(res2, 2_355_350_878_831);
-    }
+    use aoc::Solution;
 
     #[test]
     fn test_pt1() {
@@ -208,23 +200,4 @@ mod tests {
             ))
         );
     }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run2(&input));
-    }
 }
@@ -13,6 +13,7 @@ register!(
         run_any(&input, false);
         run_any(&input, true);
     }
+    answers: 218, 1908;
 );
 
 fn run_any(input: &[&[u8]], fourth_dim: bool) -> Output {
@@ -96,46 +97,6 @@ fn print_grid(col: &Grid) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 218);
-        assert_eq!(res2, 1908);
-    }
-
-    #[test]
-    fn test_pt1() {
-        assert_eq!(
-            (112, 848),
-            Solver::run_on(
-                "
-                .#.
-                ..#
-                ###
-            ",
-            )
-        );
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run_any(&input, false));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run_any(&input, true));
-    }
+    examples!("examples/day17"; ex1);
 }
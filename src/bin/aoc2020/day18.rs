@@ -6,6 +6,7 @@ register!(
         run1(&input);
         run2(&input);
     }
+    answers: 11_076_907_812_171, 283_729_053_022_731;
 );
 
 fn run1(input: &[&[u8]]) -> Output {
@@ -63,15 +64,6 @@ fn eval2(line: &[u8]) -> Output {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 11_076_907_812_171);
-        assert_eq!(res2, 283_729_053_022_731);
-    }
 
     #[test]
     fn test_eval() {
@@ -101,23 +93,4 @@ mod tests {
             eval2(b"((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2")
         );
     }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run2(&input));
-    }
 }
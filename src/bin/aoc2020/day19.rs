@@ -10,6 +10,7 @@ register!(
         run1(&input.0, &input.1);
         run2(&input.0, &input.1);
     }
+    answers: 139, 289;
 );
 
 #[derive(Debug, Clone)]
@@ -140,15 +141,9 @@ impl ProcessInput for RulesInput {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
+    use aoc::Solution;
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 139);
-        assert_eq!(res2, 289);
-    }
+    examples!("examples/day19"; ex1);
 
     #[test]
     fn test_pt1() {
@@ -170,81 +165,4 @@ mod tests {
         );
         assert_eq!(2, run1(&input.0, &input.1));
     }
-
-    #[test]
-    fn test_pt1_2() {
-        assert_eq!(
-            (3, 12),
-            Solver::run_on(
-                r#"
-                    42: 9 14 | 10 1
-                    9: 14 27 | 1 26
-                    10: 23 14 | 28 1
-                    1: "a"
-                    11: 42 31
-                    5: 1 14 | 15 1
-                    19: 14 1 | 14 14
-                    12: 24 14 | 19 1
-                    16: 15 1 | 14 14
-                    31: 14 17 | 1 13
-                    6: 14 14 | 1 14
-                    2: 1 24 | 14 4
-                    0: 8 11
-                    13: 14 3 | 1 12
-                    15: 1 | 14
-                    17: 14 2 | 1 7
-                    23: 25 1 | 22 14
-                    28: 16 1
-                    4: 1 1
-                    20: 14 14 | 1 15
-                    3: 5 14 | 16 1
-                    27: 1 6 | 14 18
-                    14: "b"
-                    21: 14 1 | 1 14
-                    25: 1 1 | 1 14
-                    22: 14 14
-                    8: 42
-                    26: 14 22 | 1 20
-                    18: 15 15
-                    7: 14 5 | 1 21
-                    24: 14 1
-
-                    abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
-                    bbabbbbaabaabba
-                    babbbbaabbbbbabbbbbbaabaaabaaa
-                    aaabbbbbbaaaabaababaabababbabaaabbababababaaa
-                    bbbbbbbaaaabbbbaaabbabaaa
-                    bbbababbbbaaaaaaaabbababaaababaabab
-                    ababaaaaaabaaab
-                    ababaaaaabbbaba
-                    baabbaaaabbaaaababbaababb
-                    abbbbabbbbaaaababbbbbbaaaababb
-                    aaaaabbaabaaaaababaa
-                    aaaabbaaaabbaaa
-                    aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
-                    babaaabbbaaabaababbaabababaaab
-                    aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba
-            "#,
-            )
-        );
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run1(&input.0, &input.1));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run2(&input.0, &input.1));
-    }
 }
@@ -5,10 +5,11 @@ type Output = u64;
 
 register!(
     "input/day20.txt";
-    (input: input!(process MapProcessor)) -> Output {
+    (mut input: input!(process MapProcessor)) -> Output {
         part1(&input);
         part2(input);
     }
+    answers: 47_213_728_755_493, 1599;
 );
 
 fn part1(input: &Map) -> Output {
@@ -304,9 +305,9 @@ impl Tile {
             Dir::Left => self.edges.left(),
         };
         match edges[&edge] {
-            Edge::Corner(id) if id != self.id => Some(id),
-            Edge::Border(id, _) if id != self.id => Some(id),
-            Edge::Border(_, id) if id != self.id => Some(id),
+            Edge::Corner(id) | Edge::Border(id, _) | Edge::Border(_, id) if id != self.id => {
+                Some(id)
+            }
             _ => None,
         }
     }
@@ -536,151 +537,6 @@ impl ProcessInput for MapProcessor {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 47_213_728_755_493);
-        assert_eq!(res2, 1599);
-    }
 
-    #[allow(clippy::too_many_lines)]
-    #[test]
-    fn test_ex() {
-        assert_eq!(
-            (20_899_048_083_289, 273),
-            Solver::run_on(
-                r#"
-    Tile 2311:
-    ..##.#..#.
-    ##..#.....
-    #...##..#.
-    ####.#...#
-    ##.##.###.
-    ##...#.###
-    .#.#.#..##
-    ..#....#..
-    ###...#.#.
-    ..###..###
-
-    Tile 1951:
-    #.##...##.
-    #.####...#
-    .....#..##
-    #...######
-    .##.#....#
-    .###.#####
-    ###.##.##.
-    .###....#.
-    ..#.#..#.#
-    #...##.#..
-
-    Tile 1171:
-    ####...##.
-    #..##.#..#
-    ##.#..#.#.
-    .###.####.
-    ..###.####
-    .##....##.
-    .#...####.
-    #.##.####.
-    ####..#...
-    .....##...
-
-    Tile 1427:
-    ###.##.#..
-    .#..#.##..
-    .#.##.#..#
-    #.#.#.##.#
-    ....#...##
-    ...##..##.
-    ...#.#####
-    .#.####.#.
-    ..#..###.#
-    ..##.#..#.
-
-    Tile 1489:
-    ##.#.#....
-    ..##...#..
-    .##..##...
-    ..#...#...
-    #####...#.
-    #..#.#.#.#
-    ...#.#.#..
-    ##.#...##.
-    ..##.##.##
-    ###.##.#..
-
-    Tile 2473:
-    #....####.
-    #..#.##...
-    #.##..#...
-    ######.#.#
-    .#...#.#.#
-    .#########
-    .###.#..#.
-    ########.#
-    ##...##.#.
-    ..###.#.#.
-
-    Tile 2971:
-    ..#.#....#
-    #...###...
-    #.#.###...
-    ##.##..#..
-    .#####..##
-    .#..####.#
-    #..#.#..#.
-    ..####.###
-    ..#.#.###.
-    ...#.#.#.#
-
-    Tile 2729:
-    ...#.#.#.#
-    ####.#....
-    ..#.#.....
-    ....#..#.#
-    .##..##.#.
-    .#.####...
-    ####.#.#..
-    ##.####...
-    ##..#.##..
-    #.##...##.
-
-    Tile 3079:
-    #.#.#####.
-    .#..######
-    ..#.......
-    ######....
-    ####.#..#.
-    .#...#.##.
-    #.#####.##
-    ..#.###...
-    ..#.......
-    ..#.###...
-                        "#,
-            )
-        );
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(input.clone()));
-    }
+    examples!("examples/day20"; ex1);
 }
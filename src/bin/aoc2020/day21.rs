@@ -9,6 +9,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: "2170", "nfnfk,nbgklf,clvr,fttbhdr,qjxxpr,hdsm,sjhds,xchzh";
 );
 
 fn part1(input: &AllergenList) -> Output {
@@ -105,50 +106,6 @@ impl ProcessInput for AllergensInput {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1.as_str(), "2170");
-        assert_eq!(
-            res2.as_str(),
-            "nfnfk,nbgklf,clvr,fttbhdr,qjxxpr,hdsm,sjhds,xchzh"
-        );
-    }
-
-    #[test]
-    fn test_pt1() {
-        assert_eq!(
-            (String::from("5"), String::from("mxmxvkd,sqjhc,fvjkl")),
-            Solver::run_on(
-                "
-                mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
-                trh fvjkl sbzzf mxmxvkd (contains dairy)
-                sqjhc fvjkl (contains soy)
-                sqjhc mxmxvkd sbzzf (contains fish)
-            ",
-            )
-        );
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
 
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day21"; ex1);
 }
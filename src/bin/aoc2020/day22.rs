@@ -9,10 +9,11 @@ type Output = usize;
 
 register!(
     "input/day22.txt";
-    (input: input!(process DeckInput)) -> Output {
+    (mut input: input!(process DeckInput)) -> Output {
         run1(input.0.clone(), input.1.clone());
         run2(input.0, input.1);
     }
+    answers: 29764, 32588;
 );
 
 #[derive(Debug, Clone)]
@@ -216,39 +217,8 @@ impl ProcessInput for DeckInput {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 29764);
-        assert_eq!(res2, 32588);
-    }
-
-    #[test]
-    fn test_pt1() {
-        assert_eq!(
-            (306, 291),
-            Solver::run_on(
-                "
-                Player 1:
-                9
-                2
-                6
-                3
-                1
-
-                Player 2:
-                5
-                8
-                4
-                7
-                10
-            ",
-            )
-        );
-    }
+    examples!("examples/day22"; ex1);
 
     #[test]
     fn test_deck_pop() {
@@ -283,23 +253,4 @@ mod tests {
         assert_eq!(0, deck.len());
         assert!(deck.is_empty());
     }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run1(input.0.clone(), input.1.clone()));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run2(input.0.clone(), input.1.clone()));
-    }
 }
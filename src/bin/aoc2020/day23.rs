@@ -6,6 +6,7 @@ register!(
         run1(input, 100);
         run2(input);
     }
+    answers: 97_624_853, 664_642_452_305;
 );
 
 fn run1(input: &[u8], games: u32) -> Output {
@@ -84,42 +85,11 @@ fn run_any(input: &[u8], cards: u32, games: u32) -> Vec<u32> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 97_624_853);
-        assert_eq!(res2, 664_642_452_305);
-    }
-
-    #[test]
-    fn test_ex() {
-        assert_eq!((67_384_529, 149_245_887_792), Solver::run_on("389125467"));
-    }
+    examples!("examples/day23"; ex1);
 
     #[test]
     fn test_small_ex() {
         assert_eq!(92_658_374, run1(b"389125467", 10));
     }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run1(input, 100));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run2(input));
-    }
 }
@@ -12,6 +12,7 @@ register!(
         grid.len();
         flipped(&grid).nth(100).unwrap();
     }
+    answers: 523, 4225;
 );
 
 type Tile = (i32, i32);
@@ -132,63 +133,6 @@ impl ProcessInput for Grids {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 523);
-        assert_eq!(res2, 4225);
-    }
-
-    #[test]
-    fn test_ex() {
-        assert_eq!(
-            (10, 2208),
-            Solver::run_on(
-                "
-            sesenwnenenewseeswwswswwnenewsewsw
-            neeenesenwnwwswnenewnwwsewnenwseswesw
-            seswneswswsenwwnwse
-            nwnwneseeswswnenewneswwnewseswneseene
-            swweswneswnenwsewnwneneseenw
-            eesenwseswswnenwswnwnwsewwnwsene
-            sewnenenenesenwsewnenwwwse
-            wenwwweseeeweswwwnwwe
-            wsweesenenewnwwnwsenewsenwwsesesenwne
-            neeswseenwwswnwswswnw
-            nenwswwsewswnenenewsenwsenwnesesenew
-            enewnwewneswsewnwswenweswnenwsenwsw
-            sweneswneswneneenwnewenewwneswswnese
-            swwesenesewenwneswnwwneseswwne
-            enesenwswwswneneswsenwnewswseenwsese
-            wnwnesenesenenwwnenwsewesewsesesew
-            nenewswnwewswnenesenwnesewesw
-            eneswnwswnwsenenwnwnwwseeswneewsenese
-            neswnwewnwnwseenwseesewsenwsweewe
-            wseweeenwnesenwwwswnew
-        "
-            )
-        );
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| input.len());
-    }
 
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| flipped(&input).nth(100).unwrap());
-    }
+    examples!("examples/day24"; ex1);
 }
@@ -7,6 +7,7 @@ register!(
         run1(input[0], input[1]);
         0;
     }
+    answers: 18_862_163, 0;
 );
 
 fn run1(card_pub: Output, door_pub: Output) -> Output {
@@ -26,39 +27,6 @@ fn run1(card_pub: Output, door_pub: Output) -> Output {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 18_862_163);
-        assert_eq!(res2, 0);
-    }
-
-    #[test]
-    fn test_ex() {
-        assert_eq!(
-            (14_897_079, 0),
-            Solver::run_on(
-                "
-                5764801
-                17807724
-            "
-            )
-        );
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| run1(input[0], input[1]));
-    }
+    examples!("examples/day25"; ex1);
 }
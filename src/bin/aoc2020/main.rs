@@ -1,5 +1,4 @@
-#![feature(slice_take)]
-#![feature(test)]
+#![cfg_attr(all(test, feature = "nightly"), feature(test))]
 #![warn(
     clippy::all,
     clippy::pedantic,
@@ -21,33 +20,10 @@
 
 #[macro_use]
 extern crate aoc;
-#[allow(unused_extern_crates)]
+#[cfg(all(test, feature = "nightly"))]
 extern crate test;
 
-aoc_main!(
-    1 => day01,
-    2 => day02,
-    3 => day03,
-    4 => day04,
-    5 => day05,
-    6 => day06,
-    7 => day07,
-    8 => day08,
-    9 => day09,
-    10 => day10,
-    11 => day11,
-    12 => day12,
-    13 => day13,
-    14 => day14,
-    15 => day15,
-    16 => day16,
-    17 => day17,
-    18 => day18,
-    19 => day19,
-    20 => day20,
-    21 => day21,
-    22 => day22,
-    23 => day23,
-    24 => day24,
-    25 => day25,
-);
+#[path = "mod.rs"]
+mod aoc2020;
+
+aoc_main!(aoc2020);
@@ -6,6 +6,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 394_647, 2_380_061_249;
 );
 
 fn part1(items: &[&[u8]]) -> u64 {
@@ -82,51 +83,6 @@ fn parse(bytes: &[u8]) -> Result<String, u8> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        [({(<(())[]>[[{[]{<()<>>
-        [(()[<>])]({[<{<<[]>>(
-        {([(<{}[<>[]}>{[]{[(<()>
-        (((({<>}<{<{<>}{[]{[]{}
-        [[<[([]))<([[{}[[()]]]
-        [{[{({}]{}}([{[{{{}}([]
-        {<[[]]>}<{[{[{[]{()[[[]
-        [<(<(<(<{}))><([]([]()
-        <{([([[(<>()){}]>(<<{{
-        <{([{{}}[<[[[<>{}]]]>[]]
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 26397);
-        assert_eq!(res2, 288_957);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 394_647);
-        assert_eq!(res2, 2_380_061_249);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day10"; ex1);
 }
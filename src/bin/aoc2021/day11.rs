@@ -2,10 +2,11 @@ use aoc::{lines, PuzzleInput};
 
 register!(
     "input/day11.txt";
-    (input: input!(verbatim Octo)) -> usize {
+    (mut input: input!(verbatim Octo)) -> usize {
         part1(&mut input);
         part2(&mut input);
     }
+    answers: 1661, 334;
 );
 
 fn part1(octo: &mut Octo) -> usize {
@@ -18,18 +19,6 @@ fn part2(octo: &mut Octo) -> usize {
         .unwrap()
 }
 
-#[cfg(test)]
-fn part1_standalone(mut octo: Octo) -> usize {
-    (0..100).map(|_| octo.flash()).sum()
-}
-
-#[cfg(test)]
-fn part2_standalone(mut octo: Octo) -> usize {
-    (1..usize::MAX)
-        .find(|_| octo.flash() == SIZE * SIZE)
-        .unwrap()
-}
-
 const SIZE: usize = 10;
 
 #[derive(Clone, Copy, Debug)]
@@ -56,7 +45,7 @@ impl Octo {
                         0 => {}
                         1..=8 => *x += 1,
                         _ => iterate(map, flashed, r, c),
-                    };
+                    }
                 }
             }
         }
@@ -91,51 +80,6 @@ impl PuzzleInput for Octo {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        5483143223
-        2745854711
-        5264556173
-        6141336146
-        6357385478
-        4167524645
-        2176841721
-        6882881134
-        4846848554
-        5283751526
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 1656);
-        assert_eq!(res2, 195);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 1661);
-        assert_eq!(res2, 334);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1_standalone(input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2_standalone(input));
-    }
+    examples!("examples/day11"; ex1);
 }
@@ -10,6 +10,7 @@ register!(
         cave.count_paths(false);
         cave.count_paths(true);
     }
+    answers: 5756, 144_603;
 );
 
 #[derive(Clone, Copy, Debug)]
@@ -37,8 +38,8 @@ impl Cave {
             // iterate through the to_visit set
             let mut paths = 0;
             while to_visit != 0 {
-                // the next node is the lowest 1 bit, HD 2-1, `x & (-x)`
-                let next = to_visit & to_visit.wrapping_neg();
+                // the next node is the lowest 1 bit
+                let next = to_visit.isolate_lowest_one();
                 // remove the next node from the to_visit set, HD 2-1, `x & (x - 1)`
                 to_visit &= to_visit - 1;
 
@@ -158,94 +159,6 @@ impl Parse for CaveTypeParser {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        start-A
-        start-b
-        A-c
-        A-b
-        b-d
-        A-end
-        b-end
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 10);
-        assert_eq!(res2, 36);
-    }
-
-    #[test]
-    fn test_ex2() {
-        let input = r#"
-        dc-end
-        HN-start
-        start-kj
-        dc-start
-        dc-HN
-        LN-dc
-        HN-end
-        kj-sa
-        kj-HN
-        kj-dc
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 19);
-        assert_eq!(res2, 103);
-    }
-
-    #[test]
-    fn test_ex3() {
-        let input = r#"
-        fs-end
-        he-DX
-        fs-he
-        start-DX
-        pj-DX
-        end-zg
-        zg-sl
-        zg-pj
-        pj-he
-        RW-he
-        fs-DX
-        pj-RW
-        zg-RW
-        start-pj
-        he-WI
-        zg-he
-        pj-fs
-        start-RW
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 226);
-        assert_eq!(res2, 3509);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 5756);
-        assert_eq!(res2, 144_603);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
 
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let cave = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| cave.count_paths(false));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let cave = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| cave.count_paths(true));
-    }
+    examples!("examples/day12"; ex1, ex2, ex3);
 }
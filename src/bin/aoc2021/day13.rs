@@ -10,6 +10,19 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers:
+        Output::Part1(638),
+        Output::Part2(String::from(
+            r"
+.##....##..##..#..#.###...##..###..###..
+#..#....#.#..#.#.#..#..#.#..#.#..#.#..#.
+#.......#.#....##...###..#..#.#..#.###..
+#.......#.#....#.#..#..#.####.###..#..#.
+#..#.#..#.#..#.#.#..#..#.#..#.#....#..#.
+.##...##...##..#..#.###..#..#.#....###..
+            "
+            .trim()
+        ));
 );
 
 fn part1(items: &[Input]) -> Output {
@@ -100,89 +113,6 @@ impl FromStr for In {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        6,10
-        0,14
-        9,10
-        0,3
-        10,4
-        4,11
-        6,0
-        6,12
-        4,1
-        0,13
-        10,12
-        3,4
-        3,0
-        8,4
-        1,10
-        2,14
-        8,10
-        9,0
-        
-        fold along y=7
-        fold along x=5
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, Output::Part1(17));
-        assert_eq!(
-            res2,
-            Output::Part2(String::from(
-                r#"
-#####
-#...#
-#...#
-#...#
-#####
-.....
-.....
-                "#
-                .trim()
-            ))
-        );
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, Output::Part1(638));
-        assert_eq!(
-            res2,
-            Output::Part2(String::from(
-                r#"
-.##....##..##..#..#.###...##..###..###..
-#..#....#.#..#.#.#..#..#.#..#.#..#.#..#.
-#.......#.#....##...###..#..#.#..#.###..
-#.......#.#....#.#..#..#.####.###..#..#.
-#..#.#..#.#..#.#.#..#..#.#..#.#....#..#.
-.##...##...##..#..#.###..#..#.#....###..
-                "#
-                .trim()
-            ))
-        );
-    }
 
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day13"; ex1);
 }
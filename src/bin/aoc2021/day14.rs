@@ -10,6 +10,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 2874, 5_208_377_027_195;
 );
 
 fn part1(input: &Input) -> Output {
@@ -30,8 +31,8 @@ fn count(Input { template, pairs }: &Input, rounds: usize) -> Output {
     let mut new_pairs: FxHashMap<[u8; 2], usize> =
         FxHashMap::with_capacity_and_hasher(template.len(), FxBuildHasher::default());
 
-    for pair in template.as_bytes().array_windows() {
-        *all_pairs.entry(*pair).or_default() += 1;
+    for pair in template.as_bytes().windows(2) {
+        *all_pairs.entry([pair[0], pair[1]]).or_default() += 1;
     }
     all_pairs.insert([template.as_bytes().last().copied().unwrap(), 0], 1);
 
@@ -49,7 +50,7 @@ fn count(Input { template, pairs }: &Input, rounds: usize) -> Output {
         std::mem::swap(&mut all_pairs, &mut new_pairs);
     }
 
-    if rounds % 2 != 0 {
+    if !rounds.is_multiple_of(2) {
         std::mem::swap(&mut all_pairs, &mut new_pairs);
     }
 
@@ -120,59 +121,6 @@ impl ProcessInput for InputParser {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        NNCB
-
-        CH -> B
-        HH -> N
-        CB -> H
-        NH -> C
-        HB -> C
-        HC -> B
-        HN -> C
-        NN -> C
-        BH -> H
-        NC -> B
-        NB -> B
-        BN -> B
-        BB -> N
-        BC -> B
-        CC -> N
-        CN -> C
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 1588);
-        assert_eq!(res2, 2_188_189_693_529);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 2874);
-        assert_eq!(res2, 5_208_377_027_195);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
 
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day14"; ex1);
 }
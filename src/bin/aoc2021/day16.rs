@@ -16,6 +16,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 986, 18_234_816_469_452;
 );
 
 const fn part1(packet: &Packet) -> Output {
@@ -35,7 +36,7 @@ pub struct Packet {
 #[derive(Debug)]
 struct Input<'a>(&'a Bits);
 
-impl<'a> Input<'a> {
+impl Input<'_> {
     fn load<V: Integral>(&mut self, num_bits: usize) -> V {
         let (val, input) = self.0.split_at(num_bits);
         self.0 = input;
@@ -58,7 +59,7 @@ impl<'a> Input<'a> {
             2 => self.decode_operator(version, Output::MAX, Output::min),
             3 => self.decode_operator(version, Output::MIN, Output::max),
             4 => self.decode_literal(version),
-            op @ (5 | 6 | 7) => {
+            op @ 5..=7 => {
                 let cmp = match op {
                     5 => Ordering::Greater,
                     6 => Ordering::Less,
@@ -99,7 +100,7 @@ impl<'a> Input<'a> {
                 version += packet.version;
                 val = op(val, packet.val);
             }
-        };
+        }
         Packet { version, val }
     }
 
@@ -124,12 +125,12 @@ impl FromStr for Packet {
     type Err = Infallible;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let bits = s
-            .as_bytes()
-            .array_chunks()
-            .filter_map(|[c1, c2]| {
-                let c1 = char::from(*c1).to_digit(16)? as u8;
-                let c2 = char::from(*c2).to_digit(16)? as u8;
+        let (pairs, _) = s.as_bytes().as_chunks::<2>();
+        let bits = pairs
+            .iter()
+            .filter_map(|pair| {
+                let c1 = char::from(pair[0]).to_digit(16)? as u8;
+                let c2 = char::from(pair[1]).to_digit(16)? as u8;
                 Some(c1 << 4 | c2)
             })
             .collect::<BitVec<_, Msb0>>();
@@ -142,74 +143,6 @@ type Bits = BitSlice<u8, Msb0>;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex_part1() {
-        let (res1, _) = Solver::run_on("8A004A801A8002F478");
-        assert_eq!(res1, 16);
-
-        let (res1, _) = Solver::run_on("620080001611562C8802118E34");
-        assert_eq!(res1, 12);
-
-        let (res1, _) = Solver::run_on("C0015000016115A2E0802F182340");
-        assert_eq!(res1, 23);
-
-        let (res1, _) = Solver::run_on("A0016C880162017C3686B18A3D4780");
-        assert_eq!(res1, 31);
-    }
-
-    #[test]
-    fn test_ex_part2() {
-        let (_, res2) = Solver::run_on("C200B40A82");
-        assert_eq!(res2, 3);
-
-        let (_, res2) = Solver::run_on("04005AC33890");
-        assert_eq!(res2, 54);
-
-        let (_, res2) = Solver::run_on("880086C3E88112");
-        assert_eq!(res2, 7);
-
-        let (_, res2) = Solver::run_on("CE00C43D881120");
-        assert_eq!(res2, 9);
-
-        let (_, res2) = Solver::run_on("D8005AC2A8F0");
-        assert_eq!(res2, 1);
 
-        let (_, res2) = Solver::run_on("F600BC2D8F");
-        assert_eq!(res2, 0);
-
-        let (_, res2) = Solver::run_on("9C005AC2F8F0");
-        assert_eq!(res2, 0);
-
-        let (_, res2) = Solver::run_on("9C0141080250320F1802104A08");
-        assert_eq!(res2, 1);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 986);
-        assert_eq!(res2, 18_234_816_469_452);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day16"; ex1, ex2, ex3, ex4, ex5, ex6, ex7, ex8, ex9, ex10, ex11, ex12);
 }
@@ -10,6 +10,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 12561, 3785;
 );
 
 fn part1(input: &[Output]) -> Output {
@@ -99,40 +100,6 @@ impl FromStr for Target {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"target area: x=20..30, y=-10..-5"#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 45);
-        assert_eq!(res2, 112);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 12561);
-        assert_eq!(res2, 3785);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
 
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day17"; ex1);
 }
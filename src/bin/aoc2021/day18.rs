@@ -1,4 +1,3 @@
-use const_combinations::SliceExt;
 use std::{convert::Infallible, fmt::Display, str::FromStr};
 use tap::Tap;
 
@@ -11,6 +10,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 4480, 4676;
 );
 
 fn part1(items: &[Input]) -> Output {
@@ -18,8 +18,7 @@ fn part1(items: &[Input]) -> Output {
 }
 
 fn part2(items: &[Input]) -> Output {
-    items
-        .permutations()
+    aoc::ordered_pairs(items)
         .map(|[l, r]| l.clone().fold(r.clone()).magnitude())
         .max()
         .unwrap()
@@ -96,7 +95,7 @@ impl Num {
             match num {
                 Num::Reg(ref v) if *v >= 10 => {
                     let l = v / 2;
-                    let r = (v + 1) / 2;
+                    let r = v.div_ceil(2);
                     *num = Num::of((l, r));
                     true
                 }
@@ -195,18 +194,19 @@ impl Display for Num {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
+    use aoc::Solution;
+
+    examples!("examples/day18"; ex1);
 
     #[test]
     fn test_parse() {
-        let input = r#"[1,2]
+        let input = r"[1,2]
         [[1,2],3]
         [9,[8,7]]
         [[1,9],[8,5]]
         [[[[1,2],[3,4]],[[5,6],[7,8]]],9]
         [[[9,[3,8]],[[0,9],6]],[[[3,7],[4,9]],3]]
-        [[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]"#;
+        [[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]";
 
         let expected = vec![
             Num::of((1, 2)),
@@ -345,10 +345,10 @@ mod tests {
     #[test]
     fn test_reduce_all1() {
         let nums = Solver::parse_input(
-            r#"[1,1]
+            r"[1,1]
             [2,2]
             [3,3]
-            [4,4]"#,
+            [4,4]",
         );
 
         let num = reduce_all(nums);
@@ -359,11 +359,11 @@ mod tests {
     #[test]
     fn test_reduce_all2() {
         let nums = Solver::parse_input(
-            r#"[1,1]
+            r"[1,1]
             [2,2]
             [3,3]
             [4,4]
-            [5,5]"#,
+            [5,5]",
         );
 
         let num = reduce_all(nums);
@@ -374,12 +374,12 @@ mod tests {
     #[test]
     fn test_reduce_all3() {
         let nums = Solver::parse_input(
-            r#"[1,1]
+            r"[1,1]
             [2,2]
             [3,3]
             [4,4]
             [5,5]
-            [6,6]"#,
+            [6,6]",
         );
 
         let num = reduce_all(nums);
@@ -389,7 +389,7 @@ mod tests {
 
     #[test]
     fn test_reduce_all() {
-        let input = r#"[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
+        let input = r"[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
         [7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
         [[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
         [[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
@@ -398,7 +398,7 @@ mod tests {
         [2,9]
         [1,[[[9,3],9],[[9,0],[0,7]]]]
         [[[5,[7,4]],7],1]
-        [[[[4,2],2],6],[8,7]]"#;
+        [[[[4,2],2],6],[8,7]]";
 
         let nums = Solver::parse_input(input);
 
@@ -434,53 +434,11 @@ mod tests {
     }
 
     #[test]
-    fn test_ex() {
-        let input = r#"
-        [[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-        [[[5,[2,8]],4],[5,[[9,9],0]]]
-        [6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-        [[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-        [[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-        [[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-        [[[[5,4],[7,7]],8],[[8,3],8]]
-        [[9,3],[[9,9],[6,[4,9]]]]
-        [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-        [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]
-        "#;
-
+    fn test_reduce_ex1() {
+        let input = include_str!("examples/day18.ex1.txt");
         assert_eq!(
             reduce_all(Solver::parse_input(input)),
             Num::of("[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]")
         );
-
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 4140);
-        assert_eq!(res2, 3993);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 4480);
-        assert_eq!(res2, 4676);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
     }
 }
@@ -1,4 +1,4 @@
-use std::{convert::Infallible, num::ParseIntError, str::FromStr};
+use std::str::FromStr;
 
 register!(
     "input/day2.txt";
@@ -6,6 +6,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 1_698_735, 1_594_785_890;
 );
 
 #[derive(Clone, Copy, Debug)]
@@ -16,13 +17,13 @@ pub enum Direction {
 }
 
 impl FromStr for Direction {
-    type Err = Infallible;
+    type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
             "forward" => Self::Forward,
             "down" => Self::Down,
             "up" => Self::Up,
-            _ => unreachable!(),
+            _ => return Err(format!("Unknown direction `{s}`")),
         })
     }
 }
@@ -31,11 +32,16 @@ impl FromStr for Direction {
 pub struct Command(Direction, i64);
 
 impl FromStr for Command {
-    type Err = ParseIntError;
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (direction, amount) = s.split_once(' ').unwrap();
-        Ok(Self(direction.parse().unwrap(), amount.parse()?))
+        let (direction, amount) = s
+            .split_once(' ')
+            .ok_or_else(|| String::from("Expected `<direction> <amount>`"))?;
+        let amount = amount
+            .parse()
+            .map_err(|e| format!("Invalid amount `{amount}`: {e}"))?;
+        Ok(Self(direction.parse()?, amount))
     }
 }
 
@@ -69,47 +75,6 @@ fn part2(items: &[Command]) -> i64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test_ex() {
-        let items = r#"
-forward 5
-down 5
-forward 8
-up 3
-down 8
-forward 2
-"#;
-        let (res1, res2) = Solver::run_on(items);
-        assert_eq!(res1, 150);
-        assert_eq!(res2, 900);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 1_698_735);
-        assert_eq!(res2, 1_594_785_890);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day2"; ex1);
 }
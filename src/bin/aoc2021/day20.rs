@@ -6,32 +6,21 @@ type Output = usize;
 
 register!(
     "input/day20.txt";
-    (input: Input) -> Output {
+    (mut input: Input) -> Output {
         part1(&mut input);
         part2(&mut input);
     }
+    answers: 5846, 21149;
 );
 
 fn part1(input: &mut Input) -> Output {
     input.run(2);
-    input.image.image.count_ones()
+    input.image.pixels.count_ones()
 }
 
 fn part2(input: &mut Input) -> Output {
     input.run(48);
-    input.image.image.count_ones()
-}
-
-#[cfg(test)]
-fn part1_standalone(mut input: Input) -> Output {
-    input.run(2);
-    input.image.image.count_ones()
-}
-
-#[cfg(test)]
-fn part2_standalone(mut input: Input) -> Output {
-    input.run(50);
-    input.image.image.count_ones()
+    input.image.pixels.count_ones()
 }
 
 #[derive(Debug, Clone)]
@@ -57,13 +46,13 @@ const MAX_LEN: usize = N + PADDING;
 const IMG_SIZE: usize = MAX_LEN * MAX_LEN;
 
 /// image representation as bit vector on stack
-type Bits = BitArray<[u64; (IMG_SIZE + 63) / 64], Msb0>;
+type Bits = BitArray<[u64; IMG_SIZE.div_ceil(64)], Msb0>;
 
 #[derive(Debug, Clone)]
 struct Image {
     start: usize,
     size: usize,
-    image: Bits,
+    pixels: Bits,
     next: Bits,
 }
 
@@ -86,15 +75,15 @@ impl Image {
         for _row in 0..size {
             let s = start - sz - 1;
             for start in s..s + size {
-                let code1 = usize::from(self.image[start]) << 8
-                    | usize::from(self.image[start + 1]) << 7
-                    | usize::from(self.image[start + 2]) << 6
-                    | usize::from(self.image[start + sz]) << 5
-                    | usize::from(self.image[start + sz + 1]) << 4
-                    | usize::from(self.image[start + sz + 2]) << 3
-                    | usize::from(self.image[start + sz + sz]) << 2
-                    | usize::from(self.image[start + sz + sz + 1]) << 1
-                    | usize::from(self.image[start + sz + sz + 2]);
+                let code1 = usize::from(self.pixels[start]) << 8
+                    | usize::from(self.pixels[start + 1]) << 7
+                    | usize::from(self.pixels[start + 2]) << 6
+                    | usize::from(self.pixels[start + sz]) << 5
+                    | usize::from(self.pixels[start + sz + 1]) << 4
+                    | usize::from(self.pixels[start + sz + 2]) << 3
+                    | usize::from(self.pixels[start + sz + sz]) << 2
+                    | usize::from(self.pixels[start + sz + sz + 1]) << 1
+                    | usize::from(self.pixels[start + sz + sz + 2]);
 
                 self.next.set(start + sz + 1, algorithm[code1]);
             }
@@ -103,16 +92,16 @@ impl Image {
 
         self.start = next_start;
         self.size = size;
-        std::mem::swap(&mut self.image, &mut self.next);
+        std::mem::swap(&mut self.pixels, &mut self.next);
     }
 }
 
 impl Display for Image {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let size = (self.image.len() as f64).sqrt() as usize;
-        for start in (0..self.image.len()).step_by(size) {
+        let size = (self.pixels.len() as f64).sqrt() as usize;
+        for start in (0..self.pixels.len()).step_by(size) {
             for start in start..start + size {
-                if self.image[start] {
+                if self.pixels[start] {
                     f.write_char('#')?;
                 } else {
                     f.write_char('.')?;
@@ -170,7 +159,7 @@ impl PuzzleInput for Input {
             image: Image {
                 start,
                 size,
-                image,
+                pixels: image,
                 next,
             },
         }
@@ -180,48 +169,6 @@ impl PuzzleInput for Input {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        ..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
-        
-        #..#.
-        #....
-        ##..#
-        ..#..
-        ..###
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 35);
-        assert_eq!(res2, 3351);
-    }
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 5846);
-        assert_eq!(res2, 21149);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1_standalone(input.clone()));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2_standalone(input.clone()));
-    }
+    examples!("examples/day20"; ex1);
 }
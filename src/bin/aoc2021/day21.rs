@@ -11,6 +11,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 913_560, 110_271_560_863_819;
 );
 
 fn part1(items: &[Input]) -> Output {
@@ -148,43 +149,6 @@ impl FromStr for Pos {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        Player 1 starting position: 4
-        Player 2 starting position: 8
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 739_785);
-        assert_eq!(res2, 444_356_092_776_315);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 913_560);
-        assert_eq!(res2, 110_271_560_863_819);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
 
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day21"; ex1);
 }
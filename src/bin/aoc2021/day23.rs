@@ -13,6 +13,7 @@ register!(
         part1(input.0);
         part2(input.1);
     }
+    answers: 11332, 49936;
 );
 
 fn part1(board: Board) -> Output {
@@ -27,7 +28,6 @@ fn part2(board: Board) -> Output {
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 #[repr(transparent)]
-
 struct Hallway(u32);
 
 impl Hallway {
@@ -657,29 +657,8 @@ impl PuzzleInput for Board {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        #############
-        #...........#
-        ###B#C#B#D###
-          #A#D#C#A#
-          #########
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 12521);
-        assert_eq!(res2, 44169);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 11332);
-        assert_eq!(res2, 49936);
-    }
+    examples!("examples/day23"; ex1);
 
     #[test]
     fn path_from() {
@@ -833,6 +812,8 @@ mod tests {
     #[test]
     #[cfg(not(debug_assertions))] // release mode only, otherwise it will take too long
     fn test_correctness() {
+        use aoc::SolutionExt;
+
         struct Case {
             input: String,
             top: String,
@@ -901,23 +882,4 @@ mod tests {
         }
         assert_eq!(n_fail, 0);
     }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(input.0));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(input.1));
-    }
 }
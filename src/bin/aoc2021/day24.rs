@@ -11,6 +11,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 98_491_959_997_994, 61_191_516_111_321;
 );
 
 fn part1(ops: &[Op]) -> Output {
@@ -142,11 +143,14 @@ pub enum Reg {
     Z,
 }
 
+// the whole program is parsed, but only the parameters that differ between the digits are read
+#[allow(dead_code)]
 pub enum Var {
     Imm(Num),
     Reg(Reg),
 }
 
+#[allow(dead_code)]
 pub enum Ins {
     Inp(Reg),
     Add(Reg, Var),
@@ -176,27 +180,34 @@ impl FromStr for Var {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s.parse::<Reg>() {
             Ok(reg) => Self::Reg(reg),
-            Err(_) => Self::Imm(s.parse()?),
+            Err(()) => Self::Imm(s.parse()?),
         })
     }
 }
 
 impl FromStr for Ins {
-    type Err = ParseIntError;
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (ins, args) = s.split_once(' ').unwrap();
+        let (ins, args) = s
+            .split_once(' ')
+            .ok_or_else(|| String::from("Expected `<instruction> <args>`"))?;
         let (a, b) = args.split_once(' ').unwrap_or((args, ""));
-        let a = a.parse::<Reg>().unwrap();
-        let b = b.parse::<Var>();
+        let reg = a
+            .parse::<Reg>()
+            .map_err(|()| format!("Invalid register `{a}`"))?;
+        let var = || {
+            b.parse::<Var>()
+                .map_err(|e| format!("Invalid operand `{b}`: {e}"))
+        };
         Ok(match ins {
-            "inp" => Self::Inp(a),
-            "add" => Self::Add(a, b?),
-            "mul" => Self::Mul(a, b?),
-            "div" => Self::Div(a, b?),
-            "mod" => Self::Mod(a, b?),
-            "eql" => Self::Eql(a, b?),
-            _ => unreachable!("ins: {}", s),
+            "inp" => Self::Inp(reg),
+            "add" => Self::Add(reg, var()?),
+            "mul" => Self::Mul(reg, var()?),
+            "div" => Self::Div(reg, var()?),
+            "mod" => Self::Mod(reg, var()?),
+            "eql" => Self::Eql(reg, var()?),
+            _ => return Err(format!("Unknown instruction `{ins}`")),
         })
     }
 }
@@ -227,43 +238,10 @@ impl ProcessInput for Op {
                     Ins::Add(Reg::X, Var::Imm(add_x)) => cs.add_x = add_x,
                     Ins::Add(Reg::Y, Var::Imm(add_y)) => cs.add_y = add_y,
                     _ => {}
-                };
+                }
                 Some(None)
             })
             .flatten()
             .collect()
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 98_491_959_997_994);
-        assert_eq!(res2, 61_191_516_111_321);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
@@ -6,26 +6,27 @@ type Output = usize;
 
 register!(
     "input/day25.txt";
-    (input: input!(parse Input)) -> Output {
-        part1(input);
+    (mut input: input!(parse Input)) -> Output {
+        part1(&mut input);
         part2();
     }
+    answers: 471, 0;
 );
 
-fn part1(mut grid: Vec<Input>) -> Output {
+fn part1(grid: &mut [Input]) -> Output {
     poop!("Initial state:");
-    poop!("{}", Grid(&grid));
+    poop!("{}", Grid(grid));
 
     (1..usize::MAX)
         .find(|_round| {
-            let result = !step1(&mut grid);
+            let result = !step1(grid);
 
             poop!(
                 "After {} step{}:",
                 _round,
                 if *_round == 1 { "" } else { "s" }
             );
-            poop!("{}", Grid(&grid));
+            poop!("{}", Grid(grid));
 
             result
         })
@@ -36,11 +37,11 @@ fn part2() -> Output {
     0
 }
 
-fn step1(grid: &mut Vec<Line>) -> bool {
+fn step1(grid: &mut [Line]) -> bool {
     step1_right(grid) | step1_down(grid)
 }
 
-fn step1_right(grid: &mut Vec<Line>) -> bool {
+fn step1_right(grid: &mut [Line]) -> bool {
     let swaps = (0..grid.len())
         .flat_map(|r| {
             let line = &grid[r];
@@ -65,7 +66,7 @@ fn step1_right(grid: &mut Vec<Line>) -> bool {
     moved
 }
 
-fn step1_down(grid: &mut Vec<Line>) -> bool {
+fn step1_down(grid: &mut [Line]) -> bool {
     let ro_grid = &*grid;
     let swaps = (0..ro_grid[0].len())
         .flat_map(|c| {
@@ -146,49 +147,6 @@ impl Display for Grid<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        v...>>.vv>
-        .vv>>.vv..
-        >>.>v>...v
-        >>v>>.>.v.
-        v>v.vv.v..
-        >.>>..v...
-        .vv..>.>v.
-        v.v..>>v.v
-        ....v..v.>
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 58);
-        assert_eq!(res2, 0);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 471);
-        assert_eq!(res2, 0);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(input.clone()));
-    }
 
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        b.iter(part2);
-    }
+    examples!("examples/day25"; ex1);
 }
@@ -6,10 +6,11 @@ use tap::Tap;
 
 register!(
     "input/day3.txt";
-    (input: input!(Bits)) -> u64 {
+    (mut input: input!(Bits)) -> u64 {
         part1(&input);
         part2(&mut input);
     }
+    answers: 4_160_394, 4_125_600;
 );
 
 #[derive(Clone, Copy, Debug, TryFromPrimitive)]
@@ -99,9 +100,7 @@ fn find_rating_2(mut items: &mut [Bits], select: impl Fn(isize) -> bool) -> u64
             .iter()
             .fold(0_isize, |count, bits| count.tap_mut(|c| *c += bits[pos]));
 
-        let number_of_ones = items
-            .iter_mut()
-            .partition_in_place(|n| matches!(n[pos], Bit::One));
+        let number_of_ones = partition(items, |n| matches!(n[pos], Bit::One));
 
         let (ones, zeroes) = items.split_at_mut(number_of_ones);
 
@@ -111,59 +110,21 @@ fn find_rating_2(mut items: &mut [Bits], select: impl Fn(isize) -> bool) -> u64
     unreachable!()
 }
 
+/// Moves the items that match `pred` to the front, and returns how many there are.
+fn partition<T>(items: &mut [T], pred: impl Fn(&T) -> bool) -> usize {
+    let mut front = 0;
+    for next in 0..items.len() {
+        if pred(&items[next]) {
+            items.swap(front, next);
+            front += 1;
+        }
+    }
+    front
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let items = r#"
-00100
-11110
-10110
-10111
-10101
-01111
-00111
-11100
-10000
-11001
-00010
-01010
-"#;
-        let (res1, res2) = Solver::run_on(items);
-        assert_eq!(res1, 198);
-        assert_eq!(res2, 230);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 4_160_394);
-        assert_eq!(res2, 4_125_600);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
 
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| {
-            let mut input = input.clone();
-            part2(&mut input)
-        });
-    }
+    examples!("examples/day3"; ex1);
 }
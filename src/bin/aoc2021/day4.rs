@@ -1,12 +1,12 @@
 use aoc::{lines, PuzzleInput};
-use derive_more::{Deref, DerefMut};
 
 register!(
     "input/day4.txt";
-    (input: input!(verbatim Bingo)) -> u32 {
+    (mut input: input!(verbatim Bingo)) -> u32 {
         part1(&input.draws, input.boards.clone());
         part2(&input.draws, input.boards);
     }
+    answers: 32844, 4920;
 );
 
 fn part1(draws: &[u8], boards: Vec<Board>) -> u32 {
@@ -28,10 +28,15 @@ fn part2(draws: &[u8], boards: Vec<Board>) -> u32 {
 }
 
 fn draw(boards: &mut Vec<Board>, number: u8) -> Vec<u32> {
-    boards
-        .drain_filter(move |board| board.draw(number))
-        .map(move |b| b.score() * u32::from(number))
-        .collect()
+    let mut scores = Vec::new();
+    boards.retain_mut(|board| {
+        let won = board.draw(number);
+        if won {
+            scores.push(board.score() * u32::from(number));
+        }
+        !won
+    });
+    scores
 }
 
 const BOARD_SIZE: usize = 5;
@@ -44,7 +49,7 @@ impl Board {
         let Some(pos) = self.0.iter().position(|&n| n == number) else { return false };
         self.0[pos] = 0;
         self.1 |= 1 << pos;
-        winners().iter().any(|&w| (self.1 & w) == w)
+        winners().iter().any(|&w| w & !self.1 == 0)
     }
 
     fn score(&self) -> u32 {
@@ -52,11 +57,6 @@ impl Board {
     }
 }
 
-#[derive(Clone, Deref, DerefMut)]
-struct Boards(Vec<Board>);
-
-impl Boards {}
-
 #[allow(clippy::unusual_byte_groupings)]
 fn winners() -> &'static [u32] {
     const fn generate() -> [u32; 10] {
@@ -84,6 +84,7 @@ fn winners() -> &'static [u32] {
     WINNERS.as_ref()
 }
 
+#[derive(Clone)]
 pub struct Bingo {
     draws: Vec<u8>,
     boards: Vec<Board>,
@@ -118,60 +119,6 @@ impl PuzzleInput for Bingo {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-        22 13 17 11  0
-         8  2 23  4 24
-        21  9 14 16  7
-         6 10  3 18  5
-         1 12 20 15 19
-
-         3 15  0  2 22
-         9 18 13 17  5
-        19  8  7 25 23
-        20 11 10 24  4
-        14 21 16 12  6
-
-        14 21 17 24  4
-        10 16 15  9 19
-        18  8 23 26 20
-        22 11 13  6  5
-         2  0 12  3  7
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 4512);
-        assert_eq!(res2, 1924);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 32844);
-        assert_eq!(res2, 4920);
-    }
 
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input.draws, input.boards.clone()));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input.draws, input.boards.clone()));
-    }
+    examples!("examples/day4"; ex1);
 }
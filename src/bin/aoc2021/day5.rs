@@ -6,6 +6,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 4873, 19472;
 );
 
 fn part1(items: &[VentLine]) -> usize {
@@ -61,51 +62,6 @@ impl FromStr for VentLine {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        0,9 -> 5,9
-        8,0 -> 0,8
-        9,4 -> 3,4
-        2,2 -> 2,1
-        7,0 -> 7,4
-        6,4 -> 2,0
-        0,9 -> 2,9
-        3,4 -> 1,4
-        0,0 -> 8,8
-        5,5 -> 8,2
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 5);
-        assert_eq!(res2, 12);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 4873);
-        assert_eq!(res2, 19472);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day5"; ex1);
 }
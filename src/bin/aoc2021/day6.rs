@@ -6,6 +6,7 @@ register!(
         part1(&input.0);
         part2(&input.0);
     }
+    answers: 360_610, 1_631_629_590_423;
 );
 
 fn part1(items: &[usize]) -> usize {
@@ -44,40 +45,6 @@ impl FromStr for Fishes {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test_ex() {
-        let input = r#"3,4,3,1,2"#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 5934);
-        assert_eq!(res2, 26_984_457_539);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 360_610);
-        assert_eq!(res2, 1_631_629_590_423);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input.0));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input.0));
-    }
+    examples!("examples/day6"; ex1);
 }
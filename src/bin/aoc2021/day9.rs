@@ -1,5 +1,4 @@
 use aoc::{lines, PuzzleInput};
-use derive_more::Add;
 use disjoint_sets::UnionFind;
 use fxhash::{FxBuildHasher, FxHashMap};
 use std::{cmp::Reverse, ops::AddAssign};
@@ -12,6 +11,7 @@ register!(
         part1(&wcc);
         part2(&wcc);
     }
+    answers: 564, 1_038_240;
 );
 
 fn part1(hm: &Wcc) -> u64 {
@@ -108,52 +108,9 @@ impl PuzzleInput for WccInput {
     }
 }
 
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Add)]
-struct Pos(i16, i16);
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        2199943210
-        3987894921
-        9856789892
-        8767896789
-        9899965678
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 15);
-        assert_eq!(res2, 1134);
-    }
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 564);
-        assert_eq!(res2, 1_038_240);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day9"; ex1);
 }
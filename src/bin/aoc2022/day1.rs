@@ -7,10 +7,11 @@ type Output = Input;
 
 register!(
     "input/day1.txt";
-    (input: input!(blocks input!(process Calories))) -> Output {
+    (mut input: input!(blocks input!(process Calories))) -> Output {
         part1(&input);
         part2(&mut input);
     }
+    answers: 70116, 206_582;
 );
 
 fn part1(items: &[Input]) -> Output {
@@ -40,55 +41,6 @@ impl ProcessInput for Calories {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
 
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        1000
-        2000
-        3000
-
-        4000
-
-        5000
-        6000
-
-        7000
-        8000
-        9000
-
-        10000
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 24000);
-        assert_eq!(res2, 45000);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 70116);
-        assert_eq!(res2, 206_582);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let mut input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&mut input));
-    }
+    examples!("examples/day1"; ex1);
 }
@@ -2,7 +2,7 @@
 
 use std::{cell::RefCell, cmp::Reverse};
 
-use aoc::{Parse, PuzzleInput};
+use aoc::{Parse, ParseError, PuzzleInput};
 
 type Output = u64;
 
@@ -12,6 +12,7 @@ register!(
         part1(&input.clone());
         part2(&input);
     }
+    answers: 61503, 14_081_365_540;
 );
 
 fn part1(monkeys: &[Monkey]) -> Output {
@@ -58,7 +59,7 @@ impl Monkey {
         *inspections += items.len() as u64;
         for item in items.drain(..) {
             let worry = self.op.apply(item) / N;
-            let target = self.branch[usize::from(worry % self.test == 0)];
+            let target = self.branch[usize::from(worry.is_multiple_of(self.test))];
             let worry = worry % lcm;
             monkeys[target as usize].items.borrow_mut().push(worry);
         }
@@ -86,6 +87,15 @@ impl PuzzleInput for Monkey {
     type Out<'a> = Self;
 
     fn from_input(input: &str) -> Self::Out<'_> {
+        Self::try_from_input(input).unwrap()
+    }
+
+    fn try_from_input(input: &str) -> Result<Self::Out<'_>, ParseError> {
+        fn field<'a>(line: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+            line.strip_prefix(prefix)
+                .ok_or_else(|| ParseError::new(format!("Expected `{prefix}`"), line))
+        }
+
         let mut lines = aoc::lines(input);
         let (
             Some(_monkey),
@@ -104,28 +114,28 @@ impl PuzzleInput for Monkey {
             lines.next(),
             lines.next(),
         ) else {
-            unreachable!("invalid input: {input}");
+            return Err(ParseError::new("Expected 6 lines per monkey", input));
         };
 
         let items = RefCell::new(
-            starting[16..]
+            field(starting, "Starting items: ")?
                 .split(", ")
-                .map(|s| s.parse().unwrap())
-                .collect(),
+                .map(aoc::try_parse)
+                .collect::<Result<_, _>>()?,
         );
 
-        let op = Op::parse_from(&operation[17..]);
-        let test = test[19..].parse().unwrap();
-        let if_true = if_true[25..].parse().unwrap();
-        let if_false = if_false[26..].parse().unwrap();
+        let op = Op::try_parse_from(field(operation, "Operation: new = ")?)?;
+        let test = aoc::try_parse(field(test, "Test: divisible by ")?)?;
+        let if_true = aoc::try_parse(field(if_true, "If true: throw to monkey ")?)?;
+        let if_false = aoc::try_parse(field(if_false, "If false: throw to monkey ")?)?;
         let branch = [if_false, if_true];
 
-        Self {
+        Ok(Self {
             items,
             op,
             test,
             branch,
-        }
+        })
     }
 }
 
@@ -133,21 +143,22 @@ impl Parse for Op {
     type Out<'a> = Self;
 
     fn parse_from(s: &str) -> Self::Out<'_> {
-        let ("old", op, new) = (&s[..3], &s[4..5], &s[6..]) else {
-            unreachable!("invalid operation: {s}");
+        Self::try_parse_from(s).unwrap()
+    }
+
+    fn try_parse_from(s: &str) -> Result<Self::Out<'_>, ParseError> {
+        let mut parts = s.split(' ');
+        let (Some("old"), Some(op), Some(new), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next()) else {
+            return Err(ParseError::new("Expected `old <op> <operand>`", s));
         };
 
-        match new {
-            "old" if op == "+" => Self::Mul(2),
-            "old" if op == "*" => Self::Square,
-            new => {
-                let new = new.parse().unwrap();
-                match op {
-                    "+" => Self::Add(new),
-                    "*" => Self::Mul(new),
-                    _ => unreachable!("invalid operation: {s}"),
-                }
-            }
+        match (op, new) {
+            ("+", "old") => Ok(Self::Mul(2)),
+            ("*", "old") => Ok(Self::Square),
+            ("+", new) => Ok(Self::Add(aoc::try_parse(new)?)),
+            ("*", new) => Ok(Self::Mul(aoc::try_parse(new)?)),
+            _ => Err(ParseError::new("Expected `+` or `*`", op)),
         }
     }
 }
@@ -155,69 +166,6 @@ impl Parse for Op {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-Monkey 0:
-  Starting items: 79, 98
-  Operation: new = old * 19
-  Test: divisible by 23
-    If true: throw to monkey 2
-    If false: throw to monkey 3
-
-Monkey 1:
-  Starting items: 54, 65, 75, 74
-  Operation: new = old + 6
-  Test: divisible by 19
-    If true: throw to monkey 2
-    If false: throw to monkey 0
-
-Monkey 2:
-  Starting items: 79, 60, 97
-  Operation: new = old * old
-  Test: divisible by 13
-    If true: throw to monkey 1
-    If false: throw to monkey 3
-
-Monkey 3:
-  Starting items: 74
-  Operation: new = old + 3
-  Test: divisible by 17
-    If true: throw to monkey 0
-    If false: throw to monkey 1
-        "#;
-
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 10605);
-        assert_eq!(res2, 2_713_310_158);
-    }
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 61503);
-        assert_eq!(res2, 14_081_365_540);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day11"; ex1);
 }
@@ -1,6 +1,6 @@
 use std::{collections::VecDeque, ops::ControlFlow};
 
-use aoc::PuzzleInput;
+use aoc::{grid::Grid, PuzzleInput};
 
 type Output = u32;
 
@@ -10,6 +10,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 456, 454;
 );
 
 fn part1(map: &Map) -> Output {
@@ -25,27 +26,29 @@ fn part1(map: &Map) -> Output {
 }
 
 fn part2(map: &Map) -> Output {
-    let mut smallest = u32::MAX;
-
+    let tiles = map.tiles.cells();
     map.bfs(|item| {
-        if item.dist < smallest && map.tiles[item.item as usize] == 0 {
-            smallest = item.dist;
+        if tiles[item.item as usize] == 0 {
+            ControlFlow::Break(item.dist)
+        } else {
+            ControlFlow::Continue(())
         }
-        ControlFlow::<()>::Continue(())
-    });
-
-    smallest
+    })
+    .unwrap()
 }
 
 #[derive(Debug)]
 pub struct Map {
-    tiles: Vec<u8>,
+    tiles: Grid<u8>,
     stride: u32,
     start: u32,
     target: u32,
 }
 
 impl Map {
+    /// Walks down from the target, as every path has to end there.
+    /// This is the hot loop of the day, so it stays on `u32` indices of the tiles instead of
+    /// going through [`aoc::search`] and the positions of the grid.
     fn bfs<T>(&self, mut action: impl FnMut(Item) -> ControlFlow<T>) -> Option<T> {
         let mut visited = vec![false; self.tiles.len()];
 
@@ -58,9 +61,8 @@ impl Map {
         });
 
         while let Some(next) = queue.pop_front() {
-            match action(next) {
-                ControlFlow::Break(res) => return Some(res),
-                ControlFlow::Continue(_) => {}
+            if let ControlFlow::Break(res) = action(next) {
+                return Some(res);
             }
 
             self.for_each_in_neighbor(next.item, |neighbor| {
@@ -76,11 +78,13 @@ impl Map {
 
         None
     }
+
     fn for_each_in_neighbor(&self, pos: u32, mut action: impl FnMut(u32)) {
+        let tiles = self.tiles.cells();
         let x_pos = pos % self.stride;
-        let elevation = self.tiles[pos as usize];
+        let elevation = tiles[pos as usize];
         let mut call = |pos| {
-            if elevation <= self.tiles[pos as usize] + 1 {
+            if elevation <= tiles[pos as usize] + 1 {
                 action(pos);
             }
         };
@@ -101,7 +105,7 @@ impl Map {
         }
 
         // down
-        if pos < self.tiles.len() as u32 - self.stride {
+        if pos < tiles.len() as u32 - self.stride {
             call(pos + self.stride);
         }
     }
@@ -117,34 +121,22 @@ impl PuzzleInput for Map {
     type Out<'a> = Self;
 
     fn from_input(input: &str) -> Self::Out<'_> {
-        let mut stride = u32::MAX;
-        let mut start = u32::MAX;
-        let mut target = u32::MAX;
-
-        let tiles = aoc::lines(input)
-            .flat_map(|line| {
-                stride = line.len() as u32;
-                line.bytes()
-            })
-            .enumerate()
-            .map(|(i, tile)| match tile {
-                b'S' => {
-                    start = i as u32;
-                    0
-                }
-                b'E' => {
-                    target = i as u32;
-                    25
-                }
-                otherwise => otherwise - b'a',
-            })
-            .collect();
+        let tiles = Grid::<u8>::from_input(input);
+        let start = tiles.position(|&tile| tile == b'S').unwrap();
+        let target = tiles.position(|&tile| tile == b'E').unwrap();
+
+        let tiles = tiles.map(|&tile| match tile {
+            b'S' => 0,
+            b'E' => 25,
+            otherwise => otherwise - b'a',
+        });
+        let index = |pos| u32::try_from(tiles.index_of(pos)).unwrap();
 
         Self {
+            stride: u32::try_from(tiles.width()).unwrap(),
+            start: index(start),
+            target: index(target),
             tiles,
-            stride,
-            start,
-            target,
         }
     }
 }
@@ -152,46 +144,6 @@ impl PuzzleInput for Map {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        Sabqponm
-        abcryxxl
-        accszExk
-        acctuvwj
-        abdefghi
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 31);
-        assert_eq!(res2, 29);
-    }
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 456);
-        assert_eq!(res2, 454);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day12"; ex1);
 }
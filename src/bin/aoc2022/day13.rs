@@ -1,6 +1,6 @@
 use std::{cmp::Ordering, slice::from_ref};
 
-use aoc::PuzzleInput;
+use aoc::{ParseError, PuzzleInput};
 use atoi::FromRadix10;
 use tap::Tap;
 
@@ -12,6 +12,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 5208, 25792;
 );
 
 fn part1(items: &[Input]) -> Output {
@@ -39,8 +40,9 @@ fn part2(items: &[Input]) -> Output {
         .iter()
         .enumerate()
         .filter_map(|(idx, &packet)| {
-            (std::ptr::eq(packet, &divider.left) || std::ptr::eq(packet, &divider.right))
-                .then_some(idx + 1)
+            (std::ptr::eq(packet, &raw const divider.left)
+                || std::ptr::eq(packet, &raw const divider.right))
+            .then_some(idx + 1)
         })
         .product()
 }
@@ -80,24 +82,30 @@ impl PartialEq for Packet {
 }
 
 impl Packet {
-    fn parse(input: &str) -> (Self, &str) {
-        input.strip_prefix('[').map_or_else(
-            || match u8::from_radix_10(input.as_bytes()) {
-                (_, 0) => unreachable!("invalid input: {input}"),
-                (int, used) => (Self::Int(int), &input[used..]),
-            },
-            |mut input| {
-                let mut list = Vec::new();
-                loop {
-                    if let Some(rest) = input.strip_prefix(']') {
-                        break (Self::List(list.leak()), rest);
-                    }
-                    let (item, rest) = Self::parse(input);
-                    list.push(item);
-                    input = rest.trim_start_matches(',');
-                }
-            },
-        )
+    fn parse(input: &str) -> Result<(Self, &str), ParseError> {
+        let Some(mut input) = input.strip_prefix('[') else {
+            return match u8::from_radix_10(input.as_bytes()) {
+                (_, 0) => Err(ParseError::new("Expected a number or a list", input)),
+                (int, used) => Ok((Self::Int(int), &input[used..])),
+            };
+        };
+
+        let mut list = Vec::new();
+        loop {
+            if let Some(rest) = input.strip_prefix(']') {
+                break Ok((Self::List(list.leak()), rest));
+            }
+            let (item, rest) = Self::parse(input)?;
+            list.push(item);
+            input = rest.trim_start_matches(',');
+        }
+    }
+
+    fn parse_line(input: &str) -> Result<Self, ParseError> {
+        match Self::parse(input)? {
+            (packet, "") => Ok(packet),
+            (_, remainder) => Err(ParseError::new("Unexpected input after packet", remainder)),
+        }
     }
 }
 
@@ -110,82 +118,25 @@ impl PuzzleInput for Input {
     type Out<'a> = Self;
 
     fn from_input(input: &str) -> Self::Out<'_> {
+        Self::try_from_input(input).unwrap()
+    }
+
+    fn try_from_input(input: &str) -> Result<Self::Out<'_>, ParseError> {
         let mut lines = aoc::lines(input);
         let (Some(left), Some(right), None) = (lines.next(), lines.next(), lines.next()) else {
-            unreachable!("invalid input: {input}");
+            return Err(ParseError::new("Expected a pair of packets", input));
         };
 
-        let (left, remainder) = Packet::parse(left);
-        assert!(remainder.is_empty());
-
-        let (right, remainder) = Packet::parse(right);
-        assert!(remainder.is_empty());
+        let left = Packet::parse_line(left)?;
+        let right = Packet::parse_line(right)?;
 
-        Self { left, right }
+        Ok(Self { left, right })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        [1,1,3,1,1]
-        [1,1,5,1,1]
-
-        [[1],[2,3,4]]
-        [[1],4]
-
-        [9]
-        [[8,7,6]]
-
-        [[4,4],4,4]
-        [[4,4],4,4,4]
 
-        [7,7,7,7]
-        [7,7,7]
-
-        []
-        [3]
-
-        [[[]]]
-        [[]]
-
-        [1,[2,[3,[4,[5,6,7]]]],8,9]
-        [1,[2,[3,[4,[5,6,0]]]],8,9]
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 13);
-        assert_eq!(res2, 140);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 5208);
-        assert_eq!(res2, 25792);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day13"; ex1);
 }
@@ -5,10 +5,11 @@ type Output = usize;
 
 register!(
     "input/day14.txt";
-    (input: input!(verbatim Map)) -> Output {
+    (mut input: input!(verbatim Map)) -> Output {
         part1(&mut input);
         part2(&mut input);
     }
+    answers: 1330, 26139;
 );
 
 const SPAWN: Coord = Coord { x: 500, y: 0 };
@@ -208,7 +209,7 @@ impl PuzzleInput for Map {
                         for y in coord.y..=prev.y {
                             blocked.block(Coord { x: coord.x, y });
                         }
-                    };
+                    }
                 } else if prev.x < coord.x {
                     for x in prev.x..=coord.x {
                         blocked.block(Coord { x, y: coord.y });
@@ -241,44 +242,6 @@ impl PuzzleInput for Map {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        498,4 -> 498,6 -> 496,6
-        503,4 -> 502,4 -> 502,9 -> 494,9
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 24);
-        assert_eq!(res2, 93);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 1330);
-        assert_eq!(res2, 26139);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&mut input.clone()));
-    }
 
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let mut input = Solver::parse_input(Solver::puzzle_input());
-        part1(&mut input);
-        b.iter(|| part2(&mut input.clone()));
-    }
+    examples!("examples/day14"; ex1);
 }
@@ -1,13 +1,8 @@
-use std::{
-    cmp::Reverse,
-    ops::{ControlFlow, RangeInclusive},
-    result::Result,
-};
+use std::ops::RangeInclusive;
 
-use aoc::{Parse, ProcessInput};
+use aoc::{interval::IntervalSet, Parse, ProcessInput};
 use atoi::FromRadix10Signed;
 use fxhash::{FxHashMap, FxHashSet};
-use tap::Tap;
 
 type Int = i32;
 type UInt = u32;
@@ -20,37 +15,28 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 5_511_201, 11_318_723_411_840;
 );
 
 fn part1(input: &Input) -> Output {
-    fn range_len_because_somehow_exact_iterator_is_not_implemented_for_i64_ranges(
-        range: Range,
-    ) -> Int {
-        range.end() - range.start() + 1
-    }
-
     let target = if input.is_example() { 10 } else { 2_000_000 };
 
     let covered = input.all_line_coverages(target);
 
     let beacons = input
         .beacons_in_line(target)
-        .filter(|x| covered.iter().any(|range| range.contains(x)))
-        .count() as Int;
+        .filter(|&x| covered.contains(x))
+        .count() as Output;
 
-    covered
-        .into_iter()
-        .map(range_len_because_somehow_exact_iterator_is_not_implemented_for_i64_ranges)
-        .map(Output::from)
-        .sum::<Output>()
-        - Output::from(beacons)
+    covered.covered_len() as Output - beacons
 }
 
 fn part2(input: &Input) -> Output {
     let max = if input.is_example() { 20 } else { 4_000_000 };
 
+    let mut covered = IntervalSet::new();
     for y in (0..=max).rev() {
-        if let Some(gap) = Input::gap_in_line(input, y, 0..=max) {
+        if let Some(gap) = input.gap_in_line(y, 0..=max, &mut covered) {
             return Output::from(gap) * 4_000_000 + Output::from(y);
         }
     }
@@ -94,62 +80,23 @@ impl Input {
             .copied()
     }
 
-    fn all_line_coverages(&self, line: Int) -> Vec<Range> {
-        fn merge_range(first: &mut Range, second: Range) -> Option<Range> {
-            if first.end() < second.start() {
-                Some(std::mem::replace(first, second))
-            } else if first.end() < second.end() {
-                *first = *first.start()..=*second.end();
-                None
-            } else {
-                None
-            }
-        }
-
+    fn all_line_coverages(&self, line: Int) -> IntervalSet<Int> {
         self.sensors
             .iter()
             .filter_map(|item| item.coverage_in_line(line))
-            .collect::<Vec<_>>()
-            .tap_mut(|c| c.sort_unstable_by_key(|range| (*range.start(), Reverse(*range.end()))))
-            .into_iter()
-            .chain(Some(Int::MAX..=Int::MAX))
-            .scan(None::<Range>, |last, range| {
-                Some({
-                    match last {
-                        Some(last) => merge_range(last, range),
-                        None => std::mem::replace(last, Some(range)),
-                    }
-                })
-            })
-            .flatten()
             .collect()
     }
 
-    fn gap_in_line(&self, line: Int, bound: Range) -> Option<Int> {
-        let ranges = self
-            .sensors
-            .iter()
-            .filter_map(|item| item.coverage_in_line(line))
-            .chain(self.beacons_in_line(line).map(|x| x..=x))
-            .filter(|range| range.start() <= bound.end() && range.end() >= bound.start())
-            .collect::<Vec<_>>()
-            .tap_mut(|c| c.sort_unstable_by_key(|range| (*range.start(), Reverse(*range.end()))));
-
-        let mut ranges = ranges.into_iter();
-        let first = ranges.next().expect("line is not covered at all");
-
-        let res = ranges.try_fold(*first.end(), |end, range| {
-            let gap = end + 1;
-            if gap < *range.start() && bound.contains(&gap) {
-                ControlFlow::Break(Ok(gap))
-            } else if end > *bound.end() {
-                ControlFlow::Break(Err(end))
-            } else {
-                ControlFlow::Continue(end.max(*range.end()))
-            }
-        });
-
-        res.break_value().and_then(Result::ok)
+    /// Reuses `covered` between lines, since this runs for millions of them.
+    fn gap_in_line(&self, line: Int, bound: Range, covered: &mut IntervalSet<Int>) -> Option<Int> {
+        covered.clear();
+        covered.extend(
+            self.sensors
+                .iter()
+                .filter_map(|item| item.coverage_in_line(line))
+                .chain(self.beacons_in_line(line).map(|x| x..=x)),
+        );
+        covered.first_gap(bound)
     }
 }
 
@@ -218,56 +165,6 @@ impl Parse for InputLine {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        Sensor at x=2, y=18: closest beacon is at x=-2, y=15
-        Sensor at x=9, y=16: closest beacon is at x=10, y=16
-        Sensor at x=13, y=2: closest beacon is at x=15, y=3
-        Sensor at x=12, y=14: closest beacon is at x=10, y=16
-        Sensor at x=10, y=20: closest beacon is at x=10, y=16
-        Sensor at x=14, y=17: closest beacon is at x=10, y=16
-        Sensor at x=8, y=7: closest beacon is at x=2, y=10
-        Sensor at x=2, y=0: closest beacon is at x=2, y=10
-        Sensor at x=0, y=11: closest beacon is at x=2, y=10
-        Sensor at x=20, y=14: closest beacon is at x=25, y=17
-        Sensor at x=17, y=20: closest beacon is at x=21, y=22
-        Sensor at x=16, y=7: closest beacon is at x=15, y=3
-        Sensor at x=14, y=3: closest beacon is at x=15, y=3
-        Sensor at x=20, y=1: closest beacon is at x=15, y=3
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 26);
-        assert_eq!(res2, 56_000_011);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert!(res1 < 6_070_917, "{res1} is too large");
-        assert_eq!(res1, 5_511_201);
-        assert_eq!(res2, 11_318_723_411_840);
-    }
 
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day15"; ex1);
 }
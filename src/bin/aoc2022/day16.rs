@@ -0,0 +1,259 @@
+use aoc::{grid::Grid, Parse, ParseError, ProcessInput};
+use fxhash::FxHashMap;
+
+type Output = u32;
+
+register!(
+    "input/day16.txt";
+    (input: input!(process Input)) -> Output {
+        part1(&input);
+        part2(&input);
+    }
+    answers: 1988, 2755;
+);
+
+fn part1(input: &Input) -> Output {
+    let mut best = 0;
+    input.max_pressure(input.flows.len(), 30, 0, 0, &mut best);
+    best
+}
+
+fn part2(input: &Input) -> Output {
+    let mut best = input.best_pressures(26);
+
+    // afterwards, `best[opened]` is the most pressure from opening any subset of `opened`
+    for valve in 0..input.flows.len() {
+        let bit = 1 << valve;
+        for opened in 0..best.len() {
+            if opened & bit != 0 {
+                best[opened] = best[opened].max(best[opened ^ bit]);
+            }
+        }
+    }
+
+    // the elephant gets to open all valves that we don't
+    let all = best.len() - 1;
+    (0..best.len())
+        .map(|opened| best[opened] + best[all ^ opened])
+        .max()
+        .unwrap_or_default()
+}
+
+pub struct Input {
+    /// Flow rates of the valves that are worth opening.
+    flows: Vec<Output>,
+    /// Minutes to walk from valve `y` to valve `x`, the start at `AA` is the last valve.
+    distances: Grid<u8>,
+}
+
+impl Input {
+    /// The most pressure released by opening exactly the valves in a bitset, indexed by that set.
+    fn best_pressures(&self, minutes: u8) -> Vec<Output> {
+        let mut best = vec![0; 1 << self.flows.len()];
+        self.open_next(self.flows.len(), minutes, 0, 0, &mut best);
+        best
+    }
+
+    /// Branch and bound search for the most pressure, updates `best` with every better solution.
+    fn max_pressure(
+        &self,
+        valve: usize,
+        minutes: u8,
+        opened: usize,
+        pressure: Output,
+        best: &mut Output,
+    ) {
+        *best = (*best).max(pressure);
+
+        // opening every valve right after walking there directly would be the best case
+        let bound = pressure
+            + self
+                .closed_valves(valve, minutes, opened)
+                .map(|(_, flow, left)| flow * Output::from(left))
+                .sum::<Output>();
+        if bound <= *best {
+            return;
+        }
+
+        for (next, flow, left) in self.closed_valves(valve, minutes, opened) {
+            let pressure = pressure + flow * Output::from(left);
+            self.max_pressure(next, left, opened | 1 << next, pressure, best);
+        }
+    }
+
+    /// The valves that are still closed and reachable in time, with their flow and the minutes
+    /// left after opening them.
+    fn closed_valves(
+        &self,
+        valve: usize,
+        minutes: u8,
+        opened: usize,
+    ) -> impl Iterator<Item = (usize, Output, u8)> + '_ {
+        self.flows
+            .iter()
+            .enumerate()
+            .filter(move |&(next, _)| opened & 1 << next == 0)
+            .filter_map(move |(next, &flow)| {
+                // walk to the valve and spend one minute opening it
+                let cost = self.distances[(next, valve)] + 1;
+                let left = minutes.checked_sub(cost).filter(|&left| left > 0)?;
+                Some((next, flow, left))
+            })
+    }
+
+    fn open_next(
+        &self,
+        valve: usize,
+        minutes: u8,
+        opened: usize,
+        pressure: Output,
+        best: &mut [Output],
+    ) {
+        best[opened] = best[opened].max(pressure);
+
+        for (next, flow, left) in self.closed_valves(valve, minutes, opened) {
+            let pressure = pressure + flow * Output::from(left);
+            self.open_next(next, left, opened | 1 << next, pressure, best);
+        }
+    }
+}
+
+impl ProcessInput for Input {
+    type In = input!(Valve);
+
+    type Out<'a> = Self;
+
+    fn process(input: <Self::In as aoc::PuzzleInput>::Out<'_>) -> Self::Out<'_> {
+        Self::try_process(input).unwrap()
+    }
+
+    fn try_process(
+        input: <Self::In as aoc::PuzzleInput>::Out<'_>,
+    ) -> Result<Self::Out<'_>, ParseError> {
+        const START: Name = *b"AA";
+        const UNREACHABLE: u8 = u8::MAX / 2;
+        // one bit for every valve with flow in the sets of opened valves
+        const MAX_FLOWS: usize = 16;
+
+        let ids = input
+            .iter()
+            .enumerate()
+            .map(|(id, valve)| (valve.name, id))
+            .collect::<FxHashMap<_, _>>();
+        let id_of = |name: &Name| {
+            ids.get(name).copied().ok_or_else(|| {
+                ParseError::without_location(format!(
+                    "Unknown valve {}",
+                    String::from_utf8_lossy(name)
+                ))
+            })
+        };
+
+        // Floyd-Warshall over all valves
+        let mut all = Grid::new(input.len(), input.len(), UNREACHABLE);
+        for (id, valve) in input.iter().enumerate() {
+            all[(id, id)] = 0;
+            for tunnel in &valve.tunnels {
+                all[(id_of(tunnel)?, id)] = 1;
+            }
+        }
+        for via in 0..input.len() {
+            for from in 0..input.len() {
+                for to in 0..input.len() {
+                    let distance = all[(via, from)] + all[(to, via)];
+                    if distance < all[(to, from)] {
+                        all[(to, from)] = distance;
+                    }
+                }
+            }
+        }
+
+        // only keep the valves with flow, and the start
+        let useful = input
+            .iter()
+            .enumerate()
+            .filter(|(_, valve)| valve.flow > 0)
+            .map(|(id, _)| id)
+            .chain(Some(id_of(&START)?))
+            .collect::<Vec<_>>();
+        if useful.len() > MAX_FLOWS + 1 {
+            return Err(ParseError::without_location(format!(
+                "Expected at most {MAX_FLOWS} valves with flow"
+            )));
+        }
+
+        let flows = useful[..useful.len() - 1]
+            .iter()
+            .map(|&id| input[id].flow)
+            .collect();
+        let distances = Grid::from_fn(useful.len(), useful.len(), |(to, from)| {
+            all[(useful[to], useful[from])]
+        });
+
+        Ok(Self { flows, distances })
+    }
+}
+
+type Name = [u8; 2];
+
+pub struct Valve {
+    name: Name,
+    flow: Output,
+    tunnels: Vec<Name>,
+}
+
+impl Parse for Valve {
+    type Out<'a> = Self;
+
+    fn parse_from(input: &str) -> Self::Out<'_> {
+        Self::try_parse_from(input).unwrap()
+    }
+
+    fn try_parse_from(input: &str) -> Result<Self::Out<'_>, ParseError> {
+        fn name(name: &str) -> Result<Name, ParseError> {
+            name.as_bytes()
+                .try_into()
+                .map_err(|_| ParseError::new("Expected a valve name of two letters", name))
+        }
+
+        // Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+        let expected = || {
+            ParseError::new(
+                "Expected `Valve <name> has flow rate=<flow>; tunnels lead to valves <names>`",
+                input,
+            )
+        };
+        let rest = input.strip_prefix("Valve ").ok_or_else(expected)?;
+        let (valve, rest) = rest.split_once(" has flow rate=").ok_or_else(expected)?;
+        let (flow, rest) = rest.split_once("; ").ok_or_else(expected)?;
+        let (_, tunnels) = rest.split_once("valve").ok_or_else(expected)?;
+        let tunnels = tunnels.trim_start_matches('s').trim_start();
+
+        Ok(Self {
+            name: name(valve)?,
+            flow: aoc::try_parse(flow)?,
+            tunnels: tunnels.split(", ").map(name).collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::SolutionExt;
+
+    examples!("examples/day16"; ex1);
+
+    #[test]
+    fn test_invalid_input() {
+        let input = "Valve AA has flow rate=0; tunnels lead to valves BB\n\
+                     Valve BB has flow rate=x; tunnel leads to valve AA\n";
+        let err = Solver::try_run_on(input).unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.column(), Some(24));
+        assert_eq!(err.snippet(), "x");
+
+        let input = "Valve AA has flow rate=0; tunnel leads to valve CC\n";
+        assert!(Solver::try_run_on(input).is_err());
+    }
+}
@@ -0,0 +1,167 @@
+use aoc::{Parse, ParseError};
+use fxhash::FxHashMap;
+
+type Output = usize;
+
+register!(
+    "input/day17.txt";
+    (input: input!(first input!(Jets))) -> Output {
+        tower_height(input, 2022);
+        tower_height(input, 1_000_000_000_000);
+    }
+    answers: 3048, 1_512_166_172_117;
+);
+
+/// The rocks as they appear, two units away from the left wall.
+/// Every byte is a row, from the bottom row in the lowest byte up, and bit 6 is the left column.
+const ROCKS: [u32; 5] = [
+    0x0000_001E, // minus
+    0x0008_1C08, // plus
+    0x0004_041C, // corner
+    0x1010_1010, // bar
+    0x0000_1818, // square
+];
+
+/// How deep [`Chamber::profile`] looks into the tower when looking for a repetition.
+const PROFILE_DEPTH: u8 = 64;
+
+fn tower_height(jets: &[u8], rocks: usize) -> Output {
+    let mut chamber = Chamber::new();
+    let mut jet = 0;
+    let mut seen = FxHashMap::default();
+    let mut skipped = 0;
+
+    let mut dropped = 0;
+    while dropped < rocks {
+        let rock = dropped % ROCKS.len();
+        chamber.drop_rock(ROCKS[rock], jets, &mut jet);
+        dropped += 1;
+
+        if skipped == 0 {
+            let state = (rock, jet, chamber.profile());
+            if let Some((before, height)) = seen.insert(state, (dropped, chamber.height())) {
+                // the tower repeats itself, skip ahead as many whole cycles as possible
+                let cycle = dropped - before;
+                let cycles = (rocks - dropped) / cycle;
+                skipped = cycles * (chamber.height() - height);
+                dropped += cycles * cycle;
+            }
+        }
+    }
+
+    chamber.height() + skipped
+}
+
+pub enum Jets {}
+
+impl Parse for Jets {
+    type Out<'a> = &'a [u8];
+
+    fn parse_from(input: &str) -> Self::Out<'_> {
+        Self::try_parse_from(input).unwrap()
+    }
+
+    fn try_parse_from(input: &str) -> Result<Self::Out<'_>, ParseError> {
+        let jets = input.as_bytes();
+        if jets.is_empty() {
+            return Err(ParseError::new("Expected at least one jet", input));
+        }
+        if let Some(invalid) = jets.iter().position(|jet| !matches!(jet, b'<' | b'>')) {
+            return Err(ParseError::bytes("Expected `<` or `>`", &jets[invalid..]));
+        }
+        Ok(jets)
+    }
+}
+
+#[derive(Clone)]
+struct Chamber(Vec<u8>);
+
+impl Chamber {
+    const LEFT_WALL: u32 = 0x4040_4040;
+    const RIGHT_WALL: u32 = 0x0101_0101;
+
+    fn new() -> Self {
+        Self(Vec::with_capacity(1 << 14))
+    }
+
+    fn height(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The four rows starting at `y`, in the same layout as the rocks.
+    fn rows(&self, y: usize) -> u32 {
+        let mut rows = [0; 4];
+        let available = self.0.get(y..).unwrap_or_default();
+        let len = available.len().min(4);
+        rows[..len].copy_from_slice(&available[..len]);
+        u32::from_le_bytes(rows)
+    }
+
+    fn is_free(&self, rock: u32, y: usize) -> bool {
+        self.rows(y) & rock == 0
+    }
+
+    fn block(&mut self, rock: u32, y: usize) {
+        for (dy, row) in rock.to_le_bytes().into_iter().enumerate() {
+            if row == 0 {
+                break;
+            }
+            if y + dy == self.0.len() {
+                self.0.push(0);
+            }
+            self.0[y + dy] |= row;
+        }
+    }
+
+    /// Pushes `rock` around with the jets starting at `jet` until it comes to rest.
+    fn drop_rock(&mut self, mut rock: u32, jets: &[u8], jet: &mut usize) {
+        let mut y = self.height() + 3;
+        loop {
+            let pushed = match jets[*jet] {
+                b'<' if rock & Self::LEFT_WALL == 0 => rock << 1,
+                b'>' if rock & Self::RIGHT_WALL == 0 => rock >> 1,
+                _ => rock,
+            };
+            *jet = (*jet + 1) % jets.len();
+            if self.is_free(pushed, y) {
+                rock = pushed;
+            }
+
+            if y == 0 || !self.is_free(rock, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        self.block(rock, y);
+    }
+
+    /// How far down from the top every column is blocked, up to [`PROFILE_DEPTH`].
+    fn profile(&self) -> [u8; 7] {
+        let mut profile = [PROFILE_DEPTH; 7];
+        for (depth, row) in self.0.iter().rev().take(PROFILE_DEPTH.into()).enumerate() {
+            for (column, top) in profile.iter_mut().enumerate() {
+                if *top == PROFILE_DEPTH && row & 0x40 >> column != 0 {
+                    *top = depth as u8;
+                }
+            }
+        }
+        profile
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::SolutionExt;
+
+    examples!("examples/day17"; ex1);
+
+    #[test]
+    fn test_invalid_input() {
+        let err = Solver::try_run_on("<<>a>\n").unwrap_err();
+        assert_eq!(err.line(), Some(1));
+        assert_eq!(err.column(), Some(4));
+        assert_eq!(err.snippet(), "a>");
+    }
+}
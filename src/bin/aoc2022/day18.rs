@@ -0,0 +1,179 @@
+use std::ops::ControlFlow;
+
+use aoc::{
+    point::Point3,
+    search::{Dense, Search},
+    MinMax, Parse, ParseError, ProcessInput,
+};
+
+type Int = i32;
+type Cube = Point3<Int>;
+type Output = usize;
+
+register!(
+    "input/day18.txt";
+    (droplet: input!(process Droplet)) -> Output {
+        droplet.surface_area();
+        droplet.exterior_surface_area();
+    }
+    answers: 6316, 4956;
+);
+
+const ONE: Cube = Point3::new(1, 1, 1);
+
+const SIDES: [Cube; 6] = [
+    Point3::new(1, 0, 0),
+    Point3::new(-1, 0, 0),
+    Point3::new(0, 1, 0),
+    Point3::new(0, -1, 0),
+    Point3::new(0, 0, 1),
+    Point3::new(0, 0, -1),
+];
+
+fn sides(cube: Cube) -> impl Iterator<Item = Cube> {
+    SIDES.into_iter().map(move |side| cube + side)
+}
+
+pub struct Droplet {
+    cubes: Vec<Cube>,
+    /// The bounding box of the cubes, with a layer of air around them.
+    bounds: MinMax<Cube>,
+    size: Cube,
+    lava: Vec<bool>,
+}
+
+impl Droplet {
+    fn surface_area(&self) -> Output {
+        self.cubes
+            .iter()
+            .flat_map(|&cube| sides(cube))
+            .filter(|&side| !self.is_lava(side))
+            .count()
+    }
+
+    fn exterior_surface_area(&self) -> Output {
+        // a corner of the bounding box is always outside, and all air around the droplet is
+        // connected through the layer around it
+        let visited = Dense::new(self.lava.len(), |&cube| self.index(cube));
+        let mut faces = 0;
+        Search::with_visited(visited).bfs(
+            [self.bounds.min],
+            |&air| sides(air).filter(|&side| self.contains(side) && !self.is_lava(side)),
+            |&air, _| {
+                faces += sides(air).filter(|&side| self.is_lava(side)).count();
+                ControlFlow::<()>::Continue(())
+            },
+        );
+        faces
+    }
+
+    fn contains(&self, cube: Cube) -> bool {
+        let MinMax { min, max } = self.bounds;
+        (min.x..=max.x).contains(&cube.x)
+            && (min.y..=max.y).contains(&cube.y)
+            && (min.z..=max.z).contains(&cube.z)
+    }
+
+    fn index(&self, cube: Cube) -> usize {
+        let Point3 { x, y, z } = cube - self.bounds.min;
+        ((z * self.size.y + y) * self.size.x + x) as usize
+    }
+
+    fn is_lava(&self, cube: Cube) -> bool {
+        self.contains(cube) && self.lava[self.index(cube)]
+    }
+}
+
+impl ProcessInput for Droplet {
+    type In = input!(CubeParser);
+
+    type Out<'a> = Self;
+
+    fn process(cubes: <Self::In as aoc::PuzzleInput>::Out<'_>) -> Self::Out<'_> {
+        Self::try_process(cubes).unwrap()
+    }
+
+    fn try_process(
+        cubes: <Self::In as aoc::PuzzleInput>::Out<'_>,
+    ) -> Result<Self::Out<'_>, ParseError> {
+        if cubes.is_empty() {
+            return Err(ParseError::without_location("Expected at least one cube"));
+        }
+        let MinMax { min, max } = cubes.iter().copied().collect::<MinMax<_>>();
+
+        // the layer of air must fit around the cubes, and every index into an `Int`
+        let lengths = min.to_array().into_iter().zip(max.to_array());
+        let volume = lengths
+            .map(|(min, max)| {
+                (min > Int::MIN && max < Int::MAX)
+                    .then(|| usize::try_from(i64::from(max) - i64::from(min) + 3).ok())
+                    .flatten()
+            })
+            .try_fold(1_usize, |volume, length| volume.checked_mul(length?))
+            .filter(|&volume| Int::try_from(volume).is_ok())
+            .ok_or_else(|| ParseError::without_location("The cubes are too far apart"))?;
+
+        let bounds = MinMax {
+            min: min - ONE,
+            max: max + ONE,
+        };
+        let size = bounds.max - bounds.min + ONE;
+
+        let mut droplet = Self {
+            cubes: Vec::new(),
+            bounds,
+            size,
+            lava: vec![false; volume],
+        };
+        for &cube in &cubes {
+            let index = droplet.index(cube);
+            droplet.lava[index] = true;
+        }
+        droplet.cubes = cubes;
+        Ok(droplet)
+    }
+}
+
+pub enum CubeParser {}
+
+impl Parse for CubeParser {
+    type Out<'a> = Cube;
+
+    fn parse_from(input: &str) -> Self::Out<'_> {
+        Self::try_parse_from(input).unwrap()
+    }
+
+    fn try_parse_from(input: &str) -> Result<Self::Out<'_>, ParseError> {
+        let expected = || ParseError::new("Expected `<x>,<y>,<z>`", input);
+        let (x, rest) = input.split_once(',').ok_or_else(expected)?;
+        let (y, z) = rest.split_once(',').ok_or_else(expected)?;
+        Ok(Point3::new(
+            aoc::try_parse(x)?,
+            aoc::try_parse(y)?,
+            aoc::try_parse(z)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::SolutionExt;
+
+    examples!("examples/day18"; ex1, ex2);
+
+    #[test]
+    fn test_invalid_input() {
+        let err = Solver::try_run_on("1,1,1\n2,1\n").unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.snippet(), "2,1");
+
+        let err = Solver::try_run_on("1,1,1\n2,x,1\n").unwrap_err();
+        assert_eq!(err.column(), Some(3));
+        assert_eq!(err.snippet(), "x");
+
+        assert!(Solver::try_run_on("").is_err());
+        assert!(Solver::try_run_on("0,0,0\n2000,2000,2000\n").is_err());
+        assert!(Solver::try_run_on("0,0,2147483647\n").is_err());
+    }
+}
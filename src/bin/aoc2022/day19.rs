@@ -0,0 +1,211 @@
+use aoc::{Parse, ParseError};
+
+type Output = u32;
+
+register!(
+    "input/day19.txt";
+    (input: input!(Blueprint)) -> Output {
+        part1(&input);
+        part2(&input);
+    }
+    answers: 3456, 9792;
+);
+
+fn part1(blueprints: &[Blueprint]) -> Output {
+    blueprints
+        .iter()
+        .map(|blueprint| blueprint.id * Output::from(blueprint.max_geodes(24)))
+        .sum()
+}
+
+fn part2(blueprints: &[Blueprint]) -> Output {
+    blueprints
+        .iter()
+        .take(3)
+        .map(|blueprint| Output::from(blueprint.max_geodes(32)))
+        .product()
+}
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+type Amounts = [u16; 4];
+
+#[derive(Copy, Clone, Debug)]
+struct State {
+    minutes: u16,
+    resources: Amounts,
+    robots: Amounts,
+}
+
+pub struct Blueprint {
+    id: Output,
+    /// What every robot costs, indexed by the robot and then the resource.
+    costs: [Amounts; 4],
+    /// More robots than the most that can be spent in a minute never help, geode robots excepted.
+    max_robots: Amounts,
+}
+
+impl Blueprint {
+    fn max_geodes(&self, minutes: u16) -> u16 {
+        let start = State {
+            minutes,
+            resources: [0; 4],
+            robots: [1, 0, 0, 0],
+        };
+
+        let mut best = 0;
+        self.build_next(start, &mut best);
+        best
+    }
+
+    /// Branch and bound over the robot to build next, waiting until it can be afforded.
+    fn build_next(&self, state: State, best: &mut u16) {
+        let State {
+            minutes,
+            resources,
+            robots,
+        } = state;
+
+        // building nothing anymore
+        *best = (*best).max(resources[GEODE] + robots[GEODE] * minutes);
+
+        if self.upper_bound(state) <= *best {
+            return;
+        }
+
+        for robot in (ORE..=GEODE).rev() {
+            // enough robots, or enough stock, to afford any robot in every minute that is left
+            let most = self.max_robots[robot].saturating_mul(minutes);
+            if robots[robot] >= self.max_robots[robot]
+                || resources[robot] + robots[robot] * minutes >= most
+            {
+                continue;
+            }
+
+            // the robot is ready at the end of the minute it is built in
+            let Some(wait) = self.wait_for(robot, state) else {
+                continue;
+            };
+            let Some(minutes) = minutes.checked_sub(wait + 1).filter(|&left| left > 0) else {
+                continue;
+            };
+
+            let mut next = State { minutes, ..state };
+            let costs = self.costs[robot];
+            for ((resource, produced), cost) in next.resources.iter_mut().zip(robots).zip(costs) {
+                *resource = *resource + produced * (wait + 1) - cost;
+            }
+            next.robots[robot] += 1;
+
+            self.build_next(next, best);
+        }
+    }
+
+    /// The geodes when ore was free and one robot of every other kind could be built in every
+    /// minute, as long as there is enough clay and obsidian.
+    fn upper_bound(&self, state: State) -> u16 {
+        let State {
+            minutes,
+            mut resources,
+            mut robots,
+        } = state;
+        let obsidian_cost = self.costs[OBSIDIAN][CLAY];
+        let geode_cost = self.costs[GEODE][OBSIDIAN];
+
+        for _ in 0..minutes {
+            let geode_robot = resources[OBSIDIAN] >= geode_cost;
+            let obsidian_robot = resources[CLAY] >= obsidian_cost;
+            for (resource, produced) in resources.iter_mut().zip(robots) {
+                *resource += produced;
+            }
+            if geode_robot {
+                resources[OBSIDIAN] -= geode_cost;
+                robots[GEODE] += 1;
+            }
+            if obsidian_robot {
+                resources[CLAY] -= obsidian_cost;
+                robots[OBSIDIAN] += 1;
+            }
+            robots[CLAY] += 1;
+        }
+
+        resources[GEODE]
+    }
+
+    /// The minutes until `robot` can be afforded, `None` if it never can with the current robots.
+    fn wait_for(&self, robot: usize, state: State) -> Option<u16> {
+        let mut wait = 0;
+        for resource in ORE..GEODE {
+            let missing = self.costs[robot][resource].saturating_sub(state.resources[resource]);
+            if missing > 0 {
+                let produced = state.robots[resource];
+                if produced == 0 {
+                    return None;
+                }
+                wait = wait.max(missing.div_ceil(produced));
+            }
+        }
+        Some(wait)
+    }
+}
+
+impl Parse for Blueprint {
+    type Out<'a> = Self;
+
+    fn parse_from(input: &str) -> Self::Out<'_> {
+        Self::try_parse_from(input).unwrap()
+    }
+
+    fn try_parse_from(input: &str) -> Result<Self::Out<'_>, ParseError> {
+        // Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore.
+        // Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+        let numbers = input
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|number| !number.is_empty())
+            .map(aoc::try_parse::<u16>)
+            .collect::<Result<Vec<_>, _>>()?;
+        let &[id, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] = &numbers[..]
+        else {
+            return Err(ParseError::new(
+                "Expected a blueprint id and the costs of the four robots",
+                input,
+            ));
+        };
+
+        let costs = [
+            [ore, 0, 0, 0],
+            [clay, 0, 0, 0],
+            [obsidian_ore, obsidian_clay, 0, 0],
+            [geode_ore, 0, geode_obsidian, 0],
+        ];
+        let mut max_robots = [u16::MAX; 4];
+        for resource in [ORE, CLAY, OBSIDIAN] {
+            max_robots[resource] = costs.iter().map(|cost| cost[resource]).max().unwrap_or(0);
+        }
+
+        Ok(Self {
+            id: id.into(),
+            costs,
+            max_robots,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::SolutionExt;
+
+    examples!("examples/day19"; ex1);
+
+    #[test]
+    fn test_invalid_input() {
+        let input = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore.\n";
+        let err = Solver::try_run_on(input).unwrap_err();
+        assert_eq!(err.line(), Some(1));
+        assert_eq!(err.column(), Some(1));
+    }
+}
@@ -1,4 +1,4 @@
-use aoc::Parse;
+use aoc::{Parse, ParseError};
 
 type Output = u64;
 
@@ -8,6 +8,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 11063, 10349;
 );
 
 fn part1(items: &[Input]) -> Output {
@@ -58,60 +59,36 @@ pub struct Input([char; 2]);
 impl Parse for Input {
     type Out<'a> = Self;
     fn parse_from(value: &str) -> Self {
-        let mut chars = value.chars();
-        let (Some(fst), Some(' '), Some(snd), None) = (
-            chars.next(),
-            chars.next(),
-            chars.next(),
-            chars.next(),
-        ) else {
-            unreachable!("invalid input: {value}");
+        Self::try_parse_from(value).unwrap()
+    }
+
+    fn try_parse_from(value: &str) -> Result<Self, ParseError> {
+        let [fst, b' ', snd] = value.as_bytes() else {
+            return Err(ParseError::new("Expected two letters separated by a space", value));
         };
-        Self([fst, snd])
+        if !matches!(fst, b'A'..=b'C') {
+            return Err(ParseError::new("Expected one of `A`, `B`, or `C`", &value[..1]));
+        }
+        if !matches!(snd, b'X'..=b'Z') {
+            return Err(ParseError::new("Expected one of `X`, `Y`, or `Z`", &value[2..]));
+        }
+        Ok(Self([char::from(*fst), char::from(*snd)]))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
+    use aoc::SolutionExt;
 
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        A Y
-        B X
-        C Z
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 15);
-        assert_eq!(res2, 12);
-    }
+    examples!("examples/day2"; ex1);
 
     #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 11063);
-        assert_eq!(res2, 10349);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
+    fn test_invalid_input() {
+        let input = "A Y\nB Q\nC Z\n";
+        let err = Solver::try_run_on(input).unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.column(), Some(3));
+        assert_eq!(err.snippet(), "Q");
     }
 }
@@ -0,0 +1,206 @@
+use aoc::{ParseError, ProcessInput, PuzzleInput};
+
+type Int = i64;
+type Output = Int;
+
+register!(
+    "input/day20.txt";
+    (input: input!(process Numbers)) -> Output {
+        part1(&input);
+        part2(&input);
+    }
+    answers: 4125, 2_347_927_419_629;
+);
+
+const DECRYPTION_KEY: Int = 811_589_153;
+
+fn part1(numbers: &[Int]) -> Output {
+    grove_coordinates(numbers, 1)
+}
+
+fn part2(numbers: &[Int]) -> Output {
+    let numbers = numbers
+        .iter()
+        .map(|number| number * DECRYPTION_KEY)
+        .collect::<Vec<_>>();
+    grove_coordinates(&numbers, 10)
+}
+
+fn grove_coordinates(numbers: &[Int], rounds: usize) -> Output {
+    let mut ring = Ring::new(numbers.len());
+    for _ in 0..rounds {
+        for (index, &number) in numbers.iter().enumerate() {
+            ring.shift(index, number);
+        }
+        ring.rebalance();
+    }
+
+    let order = ring.order();
+    let zero = order
+        .iter()
+        .position(|&index| numbers[usize::from(index)] == 0)
+        .expect("no zero in the numbers");
+    [1000, 2000, 3000]
+        .into_iter()
+        .map(|offset| numbers[usize::from(order[(zero + offset) % order.len()])])
+        .sum()
+}
+
+pub enum Numbers {}
+
+impl ProcessInput for Numbers {
+    type In = input!(parse Int);
+
+    type Out<'a> = <Self::In as PuzzleInput>::Out<'a>;
+
+    fn process(numbers: <Self::In as PuzzleInput>::Out<'_>) -> Self::Out<'_> {
+        Self::try_process(numbers).unwrap()
+    }
+
+    fn try_process(
+        numbers: <Self::In as PuzzleInput>::Out<'_>,
+    ) -> Result<Self::Out<'_>, ParseError> {
+        if !numbers.contains(&0) {
+            return Err(ParseError::without_location(
+                "Expected a zero in the numbers",
+            ));
+        }
+        // the ring stores the indices as `u16`
+        if u16::try_from(numbers.len()).is_err() {
+            return Err(ParseError::without_location(format!(
+                "Expected at most {} numbers",
+                u16::MAX
+            )));
+        }
+        Ok(numbers)
+    }
+}
+
+/// The original indices of the numbers in their current order.
+/// They are split into buckets, so that moving a number only shifts the numbers in two buckets,
+/// and duplicate numbers are told apart by their index.
+struct Ring {
+    buckets: Vec<Vec<u16>>,
+    /// The bucket that every index is in.
+    bucket_of: Vec<u16>,
+    len: usize,
+}
+
+impl Ring {
+    const BUCKET_LEN: usize = 64;
+
+    fn new(len: usize) -> Self {
+        let len = u16::try_from(len).expect("too many numbers");
+        Self::from_order(&(0..len).collect::<Vec<_>>())
+    }
+
+    fn from_order(order: &[u16]) -> Self {
+        let mut bucket_of = vec![0; order.len()];
+        let buckets = order
+            .chunks(Self::BUCKET_LEN)
+            .enumerate()
+            .map(|(bucket, indices)| {
+                for &index in indices {
+                    bucket_of[usize::from(index)] = bucket as u16;
+                }
+                indices.to_vec()
+            })
+            .collect();
+
+        Self {
+            buckets,
+            bucket_of,
+            len: order.len(),
+        }
+    }
+
+    fn order(&self) -> Vec<u16> {
+        self.buckets.concat()
+    }
+
+    /// Evens out the buckets again after many shifts.
+    fn rebalance(&mut self) {
+        *self = Self::from_order(&self.order());
+    }
+
+    /// Moves the number at `index` forward by `steps`, or backward when negative.
+    fn shift(&mut self, index: usize, steps: Int) {
+        if self.len <= 1 {
+            return;
+        }
+
+        let bucket = usize::from(self.bucket_of[index]);
+        let offset = self.buckets[bucket]
+            .iter()
+            .position(|&i| usize::from(i) == index)
+            .unwrap();
+        let position = self.buckets[..bucket].iter().map(Vec::len).sum::<usize>() + offset;
+        self.buckets[bucket].remove(offset);
+
+        // without the moving number, the others form a ring of one less
+        let mut target = (position as Int + steps).rem_euclid(self.len as Int - 1) as usize;
+        let mut bucket = 0;
+        while target > self.buckets[bucket].len() {
+            target -= self.buckets[bucket].len();
+            bucket += 1;
+        }
+        self.buckets[bucket].insert(target, index as u16);
+        self.bucket_of[index] = bucket as u16;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::SolutionExt;
+
+    examples!("examples/day20"; ex1);
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(Solver::try_run_on("1\n2\n").is_err());
+        assert!(Solver::try_run_on("").is_err());
+
+        let too_many = "0\n".repeat(usize::from(u16::MAX) + 1);
+        assert!(Solver::try_run_on(&too_many).is_err());
+    }
+
+    #[test]
+    fn test_against_vec() {
+        fn mix(numbers: &[Int], rounds: usize) -> Vec<usize> {
+            let mut order = (0..numbers.len()).collect::<Vec<_>>();
+            for _ in 0..rounds {
+                for (index, &number) in numbers.iter().enumerate() {
+                    let position = order.iter().position(|&i| i == index).unwrap();
+                    order.remove(position);
+                    let target = (position as Int + number).rem_euclid(order.len() as Int);
+                    order.insert(target as usize, index);
+                }
+            }
+            order
+        }
+
+        // the same order of a ring can start anywhere
+        fn normalize(mut order: Vec<usize>) -> Vec<usize> {
+            let first = order.iter().position(|&index| index == 0).unwrap();
+            order.rotate_left(first);
+            order
+        }
+
+        // many duplicates, and steps that go around the ring several times
+        let numbers = (0..500)
+            .map(|i| (i * 7919 % 61 - 30) * 37)
+            .collect::<Vec<Int>>();
+        for rounds in [1, 3] {
+            let mut ring = Ring::new(numbers.len());
+            for _ in 0..rounds {
+                for (index, &number) in numbers.iter().enumerate() {
+                    ring.shift(index, number);
+                }
+                ring.rebalance();
+            }
+            let order = ring.order().into_iter().map(usize::from).collect();
+            assert_eq!(normalize(order), normalize(mix(&numbers, rounds)));
+        }
+    }
+}
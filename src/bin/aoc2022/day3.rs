@@ -9,6 +9,7 @@ register!(
         part1(&input);
         part2(&input);
     }
+    answers: 7878, 2760;
 );
 
 fn part1(items: &[Input]) -> Output {
@@ -20,11 +21,15 @@ fn part1(items: &[Input]) -> Output {
 }
 
 fn part2(items: &[Input]) -> Output {
-    items
+    let (groups, _) = items.as_chunks::<3>();
+    groups
         .iter()
-        .map(|Rucksack(fst, snd)| *fst | *snd)
-        .array_chunks()
-        .map(|[a, b, c]| (a & b & c))
+        .map(|group| {
+            group
+                .iter()
+                .map(|Rucksack(fst, snd)| *fst | *snd)
+                .fold(!0, |acc, items| acc & items)
+        })
         .map(u64::trailing_zeros)
         .sum()
 }
@@ -50,47 +55,6 @@ impl Parse for Rucksack {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        vJrwpWtwJgWrhcsFMMfFFhFp
-        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-        PmmdzqPrVvPwwTWBwg
-        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-        ttgJtRGJQctTZtZT
-        CrZsJsPPZsGzwwsLwLmpwMDw
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 157);
-        assert_eq!(res2, 70);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 7878);
-        assert_eq!(res2, 2760);
-    }
 
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
+    examples!("examples/day3"; ex1);
 }
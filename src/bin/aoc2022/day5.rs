@@ -1,15 +1,16 @@
 use std::rc::Rc;
 
-use aoc::{Parse, PuzzleInput};
+use aoc::{Parse, ParseError, PuzzleInput};
 
 type Output = String;
 
 register!(
     "input/day5.txt";
-    (input: input!(verbatim Input)) -> Output {
+    (mut input: input!(verbatim Input)) -> Output {
         part1(input.clone());
         part2(input);
     }
+    answers: "QNHWJVJZW", "BPCZJLFJW";
 );
 
 fn part1(mut items: Input) -> Output {
@@ -41,7 +42,13 @@ impl Stacks {
         let from = usize::from(from);
         let to = usize::from(to);
 
-        let [from, to] = self.stacks.get_many_mut([from, to]).unwrap();
+        let (from, to) = if from < to {
+            let (before, after) = self.stacks.split_at_mut(to);
+            (&mut before[from], &mut after[0])
+        } else {
+            let (before, after) = self.stacks.split_at_mut(from);
+            (&mut after[0], &mut before[to])
+        };
 
         let cutoff_point = from.len().wrapping_sub(usize::from(amount));
 
@@ -70,17 +77,29 @@ struct Stacks {
 impl Parse for Stacks {
     type Out<'a> = Self;
     fn parse_from(value: &str) -> Self {
-        let (input, ids) = value.rsplit_once('\n').unwrap();
+        Self::try_parse_from(value).unwrap()
+    }
+
+    fn try_parse_from(value: &str) -> Result<Self, ParseError> {
+        let (input, ids) = value
+            .rsplit_once('\n')
+            .ok_or_else(|| ParseError::new("Expected a line of stack ids below the stacks", value))?;
 
         let ids = ids
             .char_indices()
-            .filter_map(|(i, c)| c.to_digit(10).map(|d| (d as u8 - 1, i)))
+            .filter(|(_, c)| c.is_ascii_digit())
             .enumerate()
-            .map(|(idx, (col, pos))| {
-                assert!(idx == usize::from(col));
-                pos
+            .map(|(idx, (pos, c))| {
+                if c.to_digit(10) == Some(idx as u32 + 1) {
+                    Ok(pos)
+                } else {
+                    Err(ParseError::new(
+                        format!("Expected stack id {}", idx + 1),
+                        &ids[pos..],
+                    ))
+                }
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut stacks = vec![Vec::with_capacity(64); ids.len()];
 
@@ -96,7 +115,7 @@ impl Parse for Stacks {
             }
         });
 
-        Self { stacks }
+        Ok(Self { stacks })
     }
 }
 
@@ -107,20 +126,28 @@ struct Instruction {
     to: u8,
 }
 
-impl From<&str> for Instruction {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        fn stack(id: &str) -> Result<u8, ParseError> {
+            aoc::try_parse::<u8>(id)?
+                .checked_sub(1)
+                .ok_or_else(|| ParseError::new("Stack ids start at 1", id))
+        }
+
         let mut parts = input.split_ascii_whitespace();
 
         let (Some("move"), Some(amount), Some("from"), Some(from), Some("to"), Some(to), None) =
         (parts.next(), parts.next(), parts.next(), parts.next(), parts.next(), parts.next(), parts.next()) else {
-            unreachable!("Invalid input: {input}");
+            return Err(ParseError::new("Expected `move <amount> from <stack> to <stack>`", input));
         };
 
-        let amount = amount.parse().unwrap();
-        let from = from.parse::<u8>().unwrap() - 1;
-        let to = to.parse::<u8>().unwrap() - 1;
+        let amount = aoc::try_parse(amount)?;
+        let from = stack(from)?;
+        let to = stack(to)?;
 
-        Self { amount, from, to }
+        Ok(Self { amount, from, to })
     }
 }
 
@@ -128,65 +155,29 @@ impl PuzzleInput for Input {
     type Out<'a> = Self;
 
     fn from_input(input: &str) -> Self::Out<'_> {
-        let (stacks, procedure) = input.split_once("\n\n").unwrap();
+        Self::try_from_input(input).unwrap()
+    }
+
+    fn try_from_input(input: &str) -> Result<Self::Out<'_>, ParseError> {
+        let (stacks, procedure) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::new("Expected stacks and procedure separated by an empty line", input))?;
 
-        let stacks = Stacks::parse_from(stacks);
-        let instructions = aoc::lines(procedure).map(Instruction::from).collect();
+        let stacks = Stacks::try_parse_from(stacks)?;
+        let instructions = aoc::lines(procedure)
+            .map(Instruction::try_from)
+            .collect::<Result<_, _>>()?;
 
-        Self {
+        Ok(Self {
             stacks,
             instructions,
-        }
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-    [D]
-[N] [C]
-[Z] [M] [P]
- 1   2   3
-
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, "CMZ");
-        assert_eq!(res2, "MCD");
-    }
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, "QNHWJVJZW");
-        assert_eq!(res2, "BPCZJLFJW");
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(input.clone()));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(input.clone()));
-    }
+    examples!("examples/day5"; ex1);
 }
@@ -1,4 +1,5 @@
-use std::simd::{u8x16, u8x4, Simd, SimdPartialEq};
+#[cfg(feature = "nightly")]
+use std::simd::{cmp::SimdPartialEq, u8x16, u8x4, Simd};
 
 type Input = u8;
 type Output = usize;
@@ -9,12 +10,15 @@ register!(
         part1(input);
         part2(input);
     }
+    answers: 1896, 3452;
 );
 
+#[cfg(feature = "nightly")]
 fn part1(items: &[Input]) -> Output {
     items
-        .array_windows::<4>()
-        .position(|&chars| {
+        .windows(4)
+        .position(|chars| {
+            let chars = <[u8; 4]>::try_from(chars).unwrap();
             let word = u8x4::from_array(chars);
             for (pos, c) in chars.into_iter().enumerate() {
                 let mut c = u8x4::splat(c);
@@ -29,11 +33,13 @@ fn part1(items: &[Input]) -> Output {
         + 4
 }
 
+#[cfg(feature = "nightly")]
 fn part2(items: &[Input]) -> Output {
     let gather = Simd::from_array([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
     items
-        .array_windows::<14>()
-        .position(|&chars| {
+        .windows(14)
+        .position(|chars| {
+            let chars = <[u8; 14]>::try_from(chars).unwrap();
             let word = u8x16::gather_or_default(&chars, gather);
             for (pos, c) in chars.into_iter().enumerate() {
                 let mut c = u8x16::splat(c);
@@ -48,59 +54,34 @@ fn part2(items: &[Input]) -> Output {
         + 14
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        mjqjpqmgbljsphdztnvjfqwrcgsmlb
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 7);
-        assert_eq!(res2, 19);
-    }
-
-    #[test]
-    fn test_more_ex1() {
-        for (input, part1, part2) in [
-            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
-            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
-            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
-            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
-        ] {
-            let (res1, res2) = Solver::run_on(input);
-            assert_eq!(res1, part1);
-            assert_eq!(res2, part2);
-        }
-    }
+#[cfg(not(feature = "nightly"))]
+fn part1(items: &[Input]) -> Output {
+    first_marker(items, 4)
+}
 
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 1896);
-        assert_eq!(res2, 3452);
-    }
+#[cfg(not(feature = "nightly"))]
+fn part2(items: &[Input]) -> Output {
+    first_marker(items, 14)
+}
 
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
+/// The position after the first `len` characters that are all different.
+#[cfg(not(feature = "nightly"))]
+fn first_marker(items: &[Input], len: usize) -> Output {
+    items
+        .windows(len)
+        .position(|chars| {
+            chars
+                .iter()
+                .enumerate()
+                .all(|(pos, c)| !chars[pos + 1..].contains(c))
+        })
+        .unwrap()
+        + len
+}
 
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(input));
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(input));
-    }
+    examples!("examples/day6"; ex1, ex2, ex3, ex4, ex5);
 }
@@ -0,0 +1,377 @@
+use std::{path::PathBuf, str::FromStr};
+
+use crate::{input::Source, report::Format, Parts};
+
+const FIRST_YEAR: u16 = 2015;
+const LAST_DAY: u8 = 25;
+
+const USAGE: &str = "\
+Usage: aoc [COMMAND] [OPTIONS] [YEAR...] [DAYS...]
+
+Runs the selected days of the selected years, or everything if nothing is selected.
+
+Commands:
+  run       solve the selected days, the default
+  bench     measure parsing and both parts of the selected days
+  readme    render the benchmark tables of README.tpl.md into README.md,
+            from the results of `aoc bench --format csv`
+  fetch     download the inputs of the selected days that are not there yet
+  new       create and register the module and input file of a day, e.g. `aoc new 2022 16`,
+            and the binary of the year if it does not exist yet
+  submit    solve one part and submit the answer, e.g. `aoc submit 2022 5 1`,
+            an answer that was already submitted is never sent again
+
+Days:
+  12        a single day
+  1-10      a range of days
+  all       all implemented days
+  latest    the latest implemented day of the latest year
+  12:2      only part 2 of day 12, works with every selection above
+  ^12       exclude day 12, works with every selection above
+
+Options:
+  -i, --input <PATH>       read the input of a single day from PATH, `-` for stdin
+      --input-dir <DIR>    look up the input files in DIR/YEAR, e.g. DIR/2022/day16.txt
+  -f, --format <FORMAT>    print results as text, json (one object per line), or csv
+      --results <PATH>     read the benchmark results for `readme` from PATH, `-` for stdin
+  -e, --example            solve the examples of the selected days instead of their inputs
+  -c, --check              compare the answers with answers/YEAR.toml, fail on a wrong answer
+      --answers-dir <DIR>  look up the YEAR.toml answer files in DIR
+      --junit <PATH>       write the checked answers as JUnit XML to PATH, implies --check
+  -j, --jobs <N>           solve up to N days in parallel, 1 by default
+      --base-url <URL>     talk to URL instead of https://adventofcode.com
+  -h, --help               print this help
+";
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Command {
+    #[default]
+    Run,
+    Bench,
+    Readme,
+    Fetch,
+    New,
+    Submit,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    pub command: Command,
+    pub years: Vec<u16>,
+    pub selectors: Vec<Selector>,
+    pub input: Option<Source>,
+    pub input_dir: Option<PathBuf>,
+    pub format: Format,
+    pub results: Option<Source>,
+    pub example: bool,
+    pub check: bool,
+    pub answers_dir: Option<PathBuf>,
+    pub junit: Option<PathBuf>,
+    pub jobs: usize,
+    pub base_url: Option<String>,
+}
+
+impl Args {
+    #[must_use]
+    pub fn from_env() -> Self {
+        match Self::parse(std::env::args().skip(1)) {
+            Ok(Some(args)) => args,
+            Ok(None) => {
+                print!("{USAGE}");
+                std::process::exit(0);
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                eprintln!();
+                eprint!("{USAGE}");
+                std::process::exit(2);
+            }
+        }
+    }
+
+    /// Returns `None` if the help was requested.
+    ///
+    /// # Errors
+    /// When an argument is invalid or an option is missing its value.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut res = Self {
+            jobs: 1,
+            ..Self::default()
+        };
+        let mut args = args.into_iter().peekable();
+
+        let command = match args.peek().map(String::as_str) {
+            Some("run") => Some(Command::Run),
+            Some("bench") => Some(Command::Bench),
+            Some("readme") => Some(Command::Readme),
+            Some("fetch") => Some(Command::Fetch),
+            Some("new") => Some(Command::New),
+            Some("submit") => Some(Command::Submit),
+            _ => None,
+        };
+        if let Some(command) = command {
+            res.command = command;
+            args.next();
+        }
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--help" | "-h" => return Ok(None),
+                "--input" | "-i" => {
+                    let value = args.next().ok_or("Missing value for --input")?;
+                    res.input = Some(Source::from_arg(&value));
+                }
+                "--input-dir" => {
+                    let value = args.next().ok_or("Missing value for --input-dir")?;
+                    res.input_dir = Some(PathBuf::from(value));
+                }
+                "--format" | "-f" => {
+                    let value = args.next().ok_or("Missing value for --format")?;
+                    res.format = value.parse()?;
+                }
+                "--results" => {
+                    let value = args.next().ok_or("Missing value for --results")?;
+                    res.results = Some(Source::from_arg(&value));
+                }
+                "--example" | "-e" => res.example = true,
+                "--check" | "-c" => res.check = true,
+                "--answers-dir" => {
+                    let value = args.next().ok_or("Missing value for --answers-dir")?;
+                    res.answers_dir = Some(PathBuf::from(value));
+                }
+                "--junit" => {
+                    let value = args.next().ok_or("Missing value for --junit")?;
+                    res.junit = Some(PathBuf::from(value));
+                    res.check = true;
+                }
+                "--jobs" | "-j" => {
+                    let value = args.next().ok_or("Missing value for --jobs")?;
+                    res.jobs = match value.parse() {
+                        Ok(jobs @ 1..) => jobs,
+                        _ => return Err(format!("Invalid number of jobs `{value}`")),
+                    };
+                }
+                "--base-url" => {
+                    let value = args.next().ok_or("Missing value for --base-url")?;
+                    res.base_url = Some(value);
+                }
+                option if option.starts_with("--") => {
+                    return Err(format!("Unknown option `{option}`"));
+                }
+                arg => match arg.parse::<u16>() {
+                    Ok(year) if year >= FIRST_YEAR => res.years.push(year),
+                    _ => res.selectors.push(arg.parse()?),
+                },
+            }
+        }
+
+        match res.command {
+            Command::New => res.selectors = vec![new_selector(&res)?],
+            Command::Submit => res.selectors = vec![submit_selector(&res)?],
+            _ => {}
+        }
+
+        Ok(Some(res))
+    }
+}
+
+/// `new` takes exactly one year and day, as `YEAR DAY`.
+fn new_selector(args: &Args) -> Result<Selector, String> {
+    const USAGE: &str = "Usage: aoc new YEAR DAY";
+
+    match (args.years.as_slice(), args.selectors.as_slice()) {
+        (
+            [_],
+            [selector @ Selector {
+                exclude: false,
+                days: Days::Day(_),
+                parts: Parts::BOTH,
+            }],
+        ) => Ok(*selector),
+        _ => Err(format!("Select exactly one year and day\n{USAGE}")),
+    }
+}
+
+/// `submit` takes exactly one year, day and part, as `YEAR DAY PART` or `YEAR DAY:PART`.
+fn submit_selector(args: &Args) -> Result<Selector, String> {
+    const USAGE: &str = "Usage: aoc submit YEAR DAY PART";
+
+    if args.years.len() != 1 {
+        return Err(format!("Select exactly one year\n{USAGE}"));
+    }
+    let (day, parts) = match args.selectors.as_slice() {
+        [Selector {
+            exclude: false,
+            days: Days::Day(day),
+            parts,
+        }] if *parts != Parts::BOTH => (*day, *parts),
+        [Selector {
+            exclude: false,
+            days: Days::Day(day),
+            parts: Parts::BOTH,
+        }, Selector {
+            exclude: false,
+            days: Days::Day(part),
+            parts: Parts::BOTH,
+        }] => {
+            let parts = Parts::only(*part)
+                .ok_or_else(|| format!("Invalid part `{part}`, expected 1 or 2"))?;
+            (*day, parts)
+        }
+        _ => return Err(format!("Select exactly one day and part\n{USAGE}")),
+    };
+
+    Ok(Selector {
+        exclude: false,
+        days: Days::Day(day),
+        parts,
+    })
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Days {
+    All,
+    Latest,
+    Day(u8),
+    Range(u8, u8),
+}
+
+impl Days {
+    #[must_use]
+    pub fn contains(self, day: u8) -> bool {
+        match self {
+            Self::All => true,
+            Self::Latest => false,
+            Self::Day(d) => d == day,
+            Self::Range(from, to) => (from..=to).contains(&day),
+        }
+    }
+}
+
+/// One day selection from the command line, e.g. `3-5`, `12:2` or `^7`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Selector {
+    pub exclude: bool,
+    pub days: Days,
+    pub parts: Parts,
+}
+
+impl FromStr for Selector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (exclude, selection) = s.strip_prefix('^').map_or((false, s), |s| (true, s));
+
+        let (days, parts) = match selection.split_once(':') {
+            Some((days, part)) => {
+                let parts =
+                    part.parse().ok().and_then(Parts::only).ok_or_else(|| {
+                        format!("Invalid part `{part}` in `{s}`, expected 1 or 2")
+                    })?;
+                (days, parts)
+            }
+            None => (selection, Parts::BOTH),
+        };
+
+        let days = match days {
+            "all" => Days::All,
+            "latest" => Days::Latest,
+            days => match days.split_once('-') {
+                Some((from, to)) => {
+                    let (from, to) = (parse_day(from, s)?, parse_day(to, s)?);
+                    if from > to {
+                        return Err(format!("Invalid range `{s}`, {from} is after {to}"));
+                    }
+                    Days::Range(from, to)
+                }
+                None => Days::Day(parse_day(days, s)?),
+            },
+        };
+
+        Ok(Self {
+            exclude,
+            days,
+            parts,
+        })
+    }
+}
+
+fn parse_day(day: &str, arg: &str) -> Result<u8, String> {
+    match day.parse::<u8>() {
+        Ok(day @ 1..=LAST_DAY) => Ok(day),
+        Ok(_) => Err(format!(
+            "Invalid day `{day}` in `{arg}`, expected 1 to {LAST_DAY}"
+        )),
+        Err(_) => Err(format!("Invalid argument `{arg}`")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selector(exclude: bool, days: Days, parts: Parts) -> Selector {
+        Selector {
+            exclude,
+            days,
+            parts,
+        }
+    }
+
+    #[test]
+    fn test_selector() {
+        let part2 = Parts::only(2).unwrap();
+        for (arg, expected) in [
+            ("12", selector(false, Days::Day(12), Parts::BOTH)),
+            ("3-5", selector(false, Days::Range(3, 5), Parts::BOTH)),
+            ("7-7", selector(false, Days::Range(7, 7), Parts::BOTH)),
+            ("all", selector(false, Days::All, Parts::BOTH)),
+            ("latest", selector(false, Days::Latest, Parts::BOTH)),
+            ("12:2", selector(false, Days::Day(12), part2)),
+            ("^12", selector(true, Days::Day(12), Parts::BOTH)),
+            ("^1-10:2", selector(true, Days::Range(1, 10), part2)),
+            (
+                "latest:1",
+                selector(false, Days::Latest, Parts::only(1).unwrap()),
+            ),
+        ] {
+            assert_eq!(arg.parse::<Selector>(), Ok(expected), "{arg}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_selector() {
+        for (arg, error) in [
+            ("", "Invalid argument ``"),
+            ("x", "Invalid argument `x`"),
+            ("0", "Invalid day `0` in `0`, expected 1 to 25"),
+            ("26", "Invalid day `26` in `26`, expected 1 to 25"),
+            ("5-3", "Invalid range `5-3`, 5 is after 3"),
+            ("1-26", "Invalid day `26` in `1-26`, expected 1 to 25"),
+            ("3-", "Invalid argument `3-`"),
+            ("12:3", "Invalid part `3` in `12:3`, expected 1 or 2"),
+            ("12:", "Invalid part `` in `12:`, expected 1 or 2"),
+            ("^^12", "Invalid argument `^^12`"),
+        ] {
+            assert_eq!(arg.parse::<Selector>(), Err(error.to_owned()), "{arg}");
+        }
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = ["2022", "12:2", "^3", "--jobs", "4"].map(String::from);
+        let args = Args::parse(args).unwrap().unwrap();
+        assert_eq!(args.years, [2022]);
+        assert_eq!(
+            args.selectors,
+            [
+                selector(false, Days::Day(12), Parts::only(2).unwrap()),
+                selector(true, Days::Day(3), Parts::BOTH),
+            ]
+        );
+        assert_eq!(args.jobs, 4);
+
+        assert!(Args::parse(["99".to_owned()]).is_err());
+        assert!(Args::parse(["--jobs".to_owned(), "0".to_owned()]).is_err());
+    }
+}
@@ -0,0 +1,443 @@
+//! A client for the Advent of Code website.
+//!
+//! The session cookie is read from the [`SESSION_ENV`] environment variable, or from a
+//! `.sessioncookie` file in the crate root or in `~/.config/aoc/session`.
+//! The website can be replaced with [`BASE_URL_ENV`], e.g. to test against a local server.
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_AUTHORS"),
+    ")"
+);
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session cookie was configured.
+    NoSession,
+    /// The website did not accept the session cookie.
+    SessionExpired,
+    /// The puzzle is not available yet, `unlocks_in` is only known before asking the website.
+    NotUnlocked {
+        year: u16,
+        day: u8,
+        unlocks_in: Option<Duration>,
+    },
+    /// Any other unexpected response.
+    Status { status: u16, body: String },
+    /// The website could not be reached.
+    Transport(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSession => write!(
+                f,
+                "No session cookie found, set {SESSION_ENV} or put it into .sessioncookie"
+            ),
+            Self::SessionExpired => f.write_str(
+                "The session cookie was not accepted, log in again and update the session",
+            ),
+            Self::NotUnlocked {
+                year,
+                day,
+                unlocks_in: Some(unlocks_in),
+            } => write!(
+                f,
+                "Day {day} of {year} is not unlocked yet, it unlocks in {}",
+                humantime::format_duration(Duration::from_secs(unlocks_in.as_secs()))
+            ),
+            Self::NotUnlocked { year, day, .. } => {
+                write!(f, "Day {day} of {year} is not unlocked yet")
+            }
+            Self::Status { status, body } => {
+                let body = body.lines().next().unwrap_or_default();
+                write!(f, "Unexpected response with status {status}: {body}")
+            }
+            Self::Transport(e) => write!(f, "Could not reach the website: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// The response of the website to a submitted answer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without telling in which direction.
+    Wrong,
+    /// The last answer was submitted too recently, nothing was checked.
+    Wait(Duration),
+    /// The part is already solved, or part 1 is not solved yet.
+    WrongLevel,
+}
+
+impl Outcome {
+    #[must_use]
+    pub const fn is_wrong(self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+    }
+
+    /// Reads the outcome from the text of the response page.
+    fn parse(body: &str) -> Option<Self> {
+        if body.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if body.contains("That's not the right answer") {
+            Some(if body.contains("your answer is too high") {
+                Self::TooHigh
+            } else if body.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            })
+        } else if body.contains("You gave an answer too recently") {
+            // "You have 4m 50s left to wait.", the time is missing in some variants of the page.
+            let wait = body
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .and_then(|(wait, _)| humantime::parse_duration(wait).ok())
+                .unwrap_or(Duration::from_secs(60));
+            Some(Self::Wait(wait))
+        } else if body.contains("You don't seem to be solving the right level") {
+            Some(Self::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => f.write_str("That's the right answer"),
+            Self::TooHigh => f.write_str("That's not the right answer, it is too high"),
+            Self::TooLow => f.write_str("That's not the right answer, it is too low"),
+            Self::Wrong => f.write_str("That's not the right answer"),
+            Self::Wait(wait) => write!(
+                f,
+                "An answer was submitted too recently, wait {} before trying again",
+                humantime::format_duration(*wait)
+            ),
+            Self::WrongLevel => {
+                f.write_str("This part cannot be submitted, it is already solved or still locked")
+            }
+        }
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    /// Uses `base_url`, or [`BASE_URL_ENV`], or the real website.
+    ///
+    /// # Errors
+    /// When no session cookie is configured.
+    pub fn new(base_url: Option<String>) -> Result<Self, ClientError> {
+        let base_url = base_url
+            .or_else(|| std::env::var(BASE_URL_ENV).ok())
+            .unwrap_or_else(|| String::from(DEFAULT_BASE_URL));
+        let session = find_session().ok_or(ClientError::NoSession)?;
+
+        Ok(Self::with_session(base_url, session))
+    }
+
+    #[must_use]
+    pub fn with_session(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .redirects(0)
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            session: session.into(),
+        }
+    }
+
+    /// Downloads the puzzle input of a day.
+    ///
+    /// # Errors
+    /// When the day is not unlocked yet, the session is not valid, or the request fails.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        if let Some(unlocks_in) = unlocks_in(year, day, SystemTime::now()) {
+            return Err(ClientError::NotUnlocked {
+                year,
+                day,
+                unlocks_in: Some(unlocks_in),
+            });
+        }
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| ClientError::Transport(e.to_string())),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(match status {
+                    404 => ClientError::NotUnlocked {
+                        year,
+                        day,
+                        unlocks_in: None,
+                    },
+                    _ if body.contains("log in") => ClientError::SessionExpired,
+                    // Without a valid session, the website redirects to the login.
+                    302 | 303 => ClientError::SessionExpired,
+                    _ => ClientError::Status { status, body },
+                })
+            }
+            Err(e) => Err(ClientError::Transport(e.to_string())),
+        }
+    }
+
+    /// Submits the answer of one part of a day.
+    ///
+    /// # Errors
+    /// When the session is not valid, the request fails, or the response cannot be understood.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Outcome, ClientError> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let (status, body) = match response {
+            Ok(response) => (response.status(), response.into_string()),
+            Err(ureq::Error::Status(status, response)) => (status, response.into_string()),
+            Err(e) => return Err(ClientError::Transport(e.to_string())),
+        };
+        let body = body.map_err(|e| ClientError::Transport(e.to_string()))?;
+
+        match status {
+            200 => Outcome::parse(&body).ok_or(ClientError::Status { status, body }),
+            404 => Err(ClientError::NotUnlocked {
+                year,
+                day,
+                unlocks_in: None,
+            }),
+            302 | 303 | 400 => Err(ClientError::SessionExpired),
+            _ => Err(ClientError::Status { status, body }),
+        }
+    }
+}
+
+fn find_session() -> Option<String> {
+    let from_file = |path: PathBuf| std::fs::read_to_string(path).ok();
+
+    std::env::var(SESSION_ENV)
+        .ok()
+        .or_else(|| from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join(".sessioncookie")))
+        .or_else(|| {
+            let config = std::env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .or_else(|| {
+                    std::env::var_os("HOME").map(|home| Path::new(&home).join(".config"))
+                })?;
+            from_file(config.join("aoc").join("session"))
+        })
+        .map(|session| session.trim().to_owned())
+        .filter(|session| !session.is_empty())
+}
+
+/// Puzzles unlock at midnight EST, which is 05:00 UTC.
+fn unlocks_in(year: u16, day: u8, now: SystemTime) -> Option<Duration> {
+    let days = days_from_civil(i64::from(year), 12, i64::from(day));
+    let unlock = UNIX_EPOCH + Duration::from_secs(u64::try_from(days * 86400 + 5 * 3600).ok()?);
+    unlock
+        .duration_since(now)
+        .ok()
+        .filter(|wait| !wait.is_zero())
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Answers a single request with `status` and `body`, and returns the request head and body.
+    pub(crate) fn serve_once(
+        status: u16,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                if line == "\r\n" {
+                    break;
+                }
+                request.push_str(&line);
+            }
+            let mut content = vec![0; content_length];
+            std::io::Read::read_exact(&mut reader, &mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            let mut stream = stream;
+            write!(
+                stream,
+                "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn test_input() {
+        let (url, server) = serve_once(200, "1\n2\n3\n");
+        let client = Client::with_session(url, "cookie");
+        assert_eq!(client.input(2022, 1).unwrap(), "1\n2\n3\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input "), "{request}");
+        assert!(request.contains("session=cookie"), "{request}");
+        assert!(request.contains(USER_AGENT), "{request}");
+    }
+
+    #[test]
+    fn test_session_expired() {
+        let body = "Puzzle inputs differ by user.  Please log in to get your puzzle input.";
+        let (url, _server) = serve_once(400, body);
+        let client = Client::with_session(url, "expired");
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::SessionExpired)
+        ));
+    }
+
+    #[test]
+    fn test_not_unlocked() {
+        let body = "Please don't repeatedly request this endpoint before it unlocks!";
+        let (url, _server) = serve_once(404, body);
+        let client = Client::with_session(url, "cookie");
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::NotUnlocked {
+                unlocks_in: None,
+                ..
+            })
+        ));
+
+        let client = Client::with_session("http://127.0.0.1:1", "cookie");
+        assert!(matches!(
+            client.input(9999, 1),
+            Err(ClientError::NotUnlocked {
+                unlocks_in: Some(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_submit() {
+        let body = "<main><article><p>That's not the right answer; your answer is too low.  \
+                    Please wait one minute before trying again.</p></article></main>";
+        let (url, server) = serve_once(200, body);
+        let client = Client::with_session(url, "cookie");
+        assert_eq!(client.submit(2022, 5, 2, "1 2").unwrap(), Outcome::TooLow);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/5/answer "), "{request}");
+        assert!(request.contains("session=cookie"), "{request}");
+        assert!(request.ends_with("level=2&answer=1+2"), "{request}");
+    }
+
+    #[test]
+    fn test_outcome() {
+        let outcome = Outcome::parse;
+        assert_eq!(
+            outcome("<p>That's the right answer!  You are one gold star closer.</p>"),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            outcome("<p>That's not the right answer; your answer is too high.</p>"),
+            Some(Outcome::TooHigh)
+        );
+        assert_eq!(
+            outcome("<p>That's not the right answer.  If you're stuck, ...</p>"),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            outcome("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 50s left to wait.</p>"),
+            Some(Outcome::Wait(Duration::from_secs(290)))
+        );
+        assert_eq!(
+            outcome("<p>You don't seem to be solving the right level.  Did you already complete it?</p>"),
+            Some(Outcome::WrongLevel)
+        );
+        assert_eq!(outcome("<p>Something else</p>"), None);
+    }
+
+    #[test]
+    fn test_unlocks_in() {
+        let unlock = UNIX_EPOCH + Duration::from_secs(1_669_870_800); // 2022-12-01T05:00:00Z
+        assert_eq!(unlocks_in(2022, 1, unlock), None);
+        assert_eq!(
+            unlocks_in(2022, 1, unlock - Duration::from_secs(60)),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(unlocks_in(2022, 1, unlock + Duration::from_secs(1)), None);
+    }
+}
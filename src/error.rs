@@ -0,0 +1,253 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+const MAX_SNIPPET_LEN: usize = 40;
+
+/// An error from the fallible parsing path, e.g. [`crate::Parse::try_parse_from`].
+///
+/// Parsers create the error with the part of their input that they could not parse.
+/// Once the error reaches [`crate::Solution::try_parse_input`], that part is located
+/// within the full puzzle input, which gives the line and column numbers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    day: Option<u8>,
+    line: Option<usize>,
+    column: Option<usize>,
+    snippet: String,
+    message: String,
+    position: Option<usize>,
+}
+
+impl ParseError {
+    /// `snippet` must be a slice of the input that was given to the parser,
+    /// otherwise the error cannot be located later on.
+    pub fn new(message: impl Into<String>, snippet: &str) -> Self {
+        Self {
+            snippet: snippet
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .take(MAX_SNIPPET_LEN)
+                .collect(),
+            position: Some(snippet.as_ptr() as usize),
+            ..Self::without_location(message)
+        }
+    }
+
+    /// An error that does not point to any part of the input.
+    pub fn without_location(message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line: None,
+            column: None,
+            snippet: String::new(),
+            message: message.into(),
+            position: None,
+        }
+    }
+
+    /// Same as [`ParseError::new`], for parsers that work on bytes.
+    pub fn bytes(message: impl Into<String>, snippet: &[u8]) -> Self {
+        let end = snippet.len().min(MAX_SNIPPET_LEN);
+        let snippet_text = String::from_utf8_lossy(&snippet[..end]);
+
+        Self {
+            position: Some(snippet.as_ptr() as usize),
+            ..Self::new(message, &snippet_text)
+        }
+    }
+
+    #[must_use]
+    pub fn with_day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+
+    /// Resolves the line and column of the snippet, if it is part of `input`.
+    #[must_use]
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = self
+            .position
+            .and_then(|pos| pos.checked_sub(start))
+            .filter(|offset| input.is_char_boundary(*offset));
+
+        if let Some(offset) = offset {
+            let before = &input[..offset];
+            let line_start = before.rfind('\n').map_or(0, |nl| nl + 1);
+            self.line = Some(before.matches('\n').count() + 1);
+            self.column = Some(before[line_start..].chars().count() + 1);
+            self.position = None;
+        }
+
+        self
+    }
+
+    #[must_use]
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    #[must_use]
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    #[must_use]
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    #[must_use]
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.day {
+            Some(day) => write!(f, "Invalid input for day {day:02}")?,
+            None => f.write_str("Invalid input")?,
+        }
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            write!(f, " at line {line}, column {column}")?;
+        }
+        write!(f, ": {}", self.message)?;
+        if !self.snippet.is_empty() {
+            write!(f, " (found `{}`)", self.snippet)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `s` with its [`FromStr`] impl and turns a failure into a [`ParseError`].
+///
+/// # Errors
+/// When `s` cannot be parsed into a `T`.
+pub fn try_parse<T>(s: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    s.parse().map_err(|e| ParseError::new(format!("{e}"), s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Blocks, First, Parse, Parsing, Post, PuzzleInput, StdFromStrParsing};
+
+    /// Two numbers separated by a comma.
+    enum Pair {}
+
+    impl Parse for Pair {
+        type Out<'a> = (u32, u32);
+
+        fn parse_from(input: &str) -> Self::Out<'_> {
+            Self::try_parse_from(input).unwrap()
+        }
+
+        fn try_parse_from(input: &str) -> Result<Self::Out<'_>, ParseError> {
+            let (a, b) = input
+                .split_once(',')
+                .ok_or_else(|| ParseError::new("Expected `,`", input))?;
+            Ok((try_parse(a)?, try_parse(b)?))
+        }
+    }
+
+    fn try_parse_input<T: PuzzleInput>(input: &str) -> Result<T::Out<'_>, ParseError> {
+        T::try_from_input(input).map_err(|e| e.locate(input))
+    }
+
+    #[test]
+    fn test_locate() {
+        let input = "first line\n  second line\n";
+        let err = ParseError::new("Expected a number", &input[13..]).locate(input);
+        assert_eq!((err.line(), err.column()), (Some(2), Some(3)));
+        assert_eq!(err.snippet(), "second line");
+        assert_eq!(
+            err.to_string(),
+            "Invalid input at line 2, column 3: Expected a number (found `second line`)"
+        );
+
+        let err = ParseError::bytes("Expected `#`", &input.as_bytes()[6..]).locate(input);
+        assert_eq!((err.line(), err.column()), (Some(1), Some(7)));
+        assert_eq!(err.snippet(), "line");
+
+        // The columns count characters, not bytes.
+        let input = "äöü x";
+        let err = ParseError::new("Expected a letter", &input[7..]).locate(input);
+        assert_eq!((err.line(), err.column()), (Some(1), Some(5)));
+    }
+
+    #[test]
+    fn test_unlocated() {
+        let input = "1,2\n";
+        // A snippet from another string than the input cannot be located.
+        let err = ParseError::new("Expected a pair", &String::from("1,2")).locate(input);
+        assert_eq!((err.line(), err.column()), (None, None));
+        assert_eq!(err.snippet(), "1,2");
+
+        let err = ParseError::without_location("Expected at least one pair")
+            .locate(input)
+            .with_day(7);
+        assert_eq!((err.line(), err.column()), (None, None));
+        assert_eq!(
+            err.to_string(),
+            "Invalid input for day 07: Expected at least one pair"
+        );
+    }
+
+    #[test]
+    fn test_snippet() {
+        let line = "x".repeat(50);
+        let input = format!("{line}\nnext");
+        assert_eq!(ParseError::new("", &input).snippet(), "x".repeat(40));
+        assert_eq!(
+            ParseError::bytes("", input.as_bytes()).snippet(),
+            "x".repeat(40)
+        );
+        assert_eq!(ParseError::new("", "\nnext").snippet(), "");
+    }
+
+    #[test]
+    fn test_nested_parsers() {
+        assert_eq!(
+            try_parse_input::<Parsing<Pair>>("1,2\n3,4\n"),
+            Ok(vec![(1, 2), (3, 4)])
+        );
+
+        let err = try_parse_input::<Parsing<Pair>>("1,2\n3;4\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(2), Some(1)));
+        assert_eq!(err.message(), "Expected `,`");
+
+        let err = try_parse_input::<Blocks<Parsing<Pair>>>("1,2\n\n3,4\n5,x\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(4), Some(3)));
+        assert_eq!(err.snippet(), "x");
+
+        let input = "7\n8\n\n9\n1o\n";
+        type FirstBlock = Post<First<Blocks<StdFromStrParsing<u32>>>>;
+        assert_eq!(try_parse_input::<FirstBlock>("7\n8\n\n9\n"), Ok(vec![7, 8]));
+        let err = try_parse_input::<FirstBlock>(input).unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(5), Some(1)));
+        assert_eq!(err.snippet(), "1o");
+
+        let err = try_parse_input::<Post<First<Parsing<Pair>>>>("\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (None, None));
+        assert_eq!(err.message(), "Expected at least one item");
+    }
+}
@@ -0,0 +1,170 @@
+//! Examples from the puzzle descriptions, stored next to the days of a year.
+//!
+//! `examples/day14.ex1.txt`, `examples/day14.ex2.txt`, ... are the inputs, and
+//! `examples/day14.toml` has their answers, in the same format as the known answers:
+//!
+//! ```toml
+//! [ex1]
+//! part1 = 24
+//! part2 = 93
+//!
+//! [ex2]
+//! part2 = 36
+//! ```
+//!
+//! A part without an answer is not checked, as some examples only apply to one part.
+//! [`examples!`](crate::examples!) turns every example into a test case.
+
+use std::{
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::{self, Verdict},
+    Solution, SolutionExt,
+};
+
+/// Directory with the examples of a year, next to the days.
+pub const DIR: &str = "examples";
+
+/// The answers of both parts of an example, if known.
+pub type Expected = [Option<String>; 2];
+
+/// One example input with its answers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: Expected,
+}
+
+impl Example {
+    /// Loads the example `name` of the day at `base`, e.g. `examples/day14`.
+    ///
+    /// # Errors
+    /// When the input cannot be read, or the answers cannot be read or are invalid.
+    pub fn load(base: &Path, name: &str) -> Result<Self, String> {
+        let path = with_suffix(base, &format!(".{name}.txt"));
+        let input = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Could not read example {}: {e}", path.display()))?;
+        let expected = load_answers(base)?
+            .into_iter()
+            .find_map(|(example, expected)| (example == name).then_some(expected))
+            .unwrap_or_default();
+
+        Ok(Self {
+            name: name.to_owned(),
+            input,
+            expected,
+        })
+    }
+
+    #[must_use]
+    pub fn check(&self, part: u8, answer: &str) -> Verdict {
+        let expected = part
+            .checked_sub(1)
+            .and_then(|index| self.expected.get(usize::from(index)))
+            .and_then(Option::as_deref);
+        answers::check(expected, answer)
+    }
+}
+
+/// The directory with the examples of `year`.
+#[must_use]
+pub fn dir(year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("bin")
+        .join(format!("aoc{year}"))
+        .join(DIR)
+}
+
+/// Loads all examples of `day` in `dir`, sorted by their number.
+///
+/// # Errors
+/// When an example or the answers cannot be read, or the answers are invalid.
+pub fn load_all(dir: &Path, day: u8) -> Result<Vec<Example>, String> {
+    let prefix = format!("day{day}.");
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Could not read {}: {e}", dir.display())),
+    };
+
+    let mut names = entries
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name().into_string().ok()?;
+            let name = file_name.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+            let number = name.strip_prefix("ex")?.parse::<u32>().ok()?;
+            Some((number, name.to_owned()))
+        })
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+
+    let base = dir.join(format!("day{day}"));
+    names
+        .into_iter()
+        .map(|(_, name)| Example::load(&base, &name))
+        .collect()
+}
+
+/// The test generated by [`examples!`](crate::examples!), runs the example `name`
+/// through [`SolutionExt::run_on`] and compares the answers.
+/// `path` is relative to `source_file`, same as the input given to `register!`.
+///
+/// # Panics
+/// When the example cannot be loaded, has no answers, or an answer is wrong.
+pub fn test<S>(manifest_dir: &str, source_file: &str, path: &str, name: &str)
+where
+    S: Solution,
+    S::Output: Display,
+{
+    let base = Path::new(manifest_dir)
+        .join(source_file)
+        .parent()
+        .map_or_else(|| PathBuf::from(path), |dir| dir.join(path));
+    let example = Example::load(&base, name).unwrap_or_else(|e| panic!("{e}"));
+    assert!(
+        example.expected.iter().any(Option::is_some),
+        "No answers for `{name}` in {}",
+        with_suffix(&base, ".toml").display()
+    );
+
+    let (part1, part2) = S::run_on(&example.input);
+    for (part, answer) in [(1, part1.to_string()), (2, part2.to_string())] {
+        if let Verdict::Wrong { expected } = example.check(part, &answer) {
+            panic!(
+                "Wrong answer for part {part} of `{name}`\nexpected: {expected}\n  actual: {}",
+                answer.trim()
+            );
+        }
+    }
+}
+
+/// The answers of all examples of a day, a missing file means that none are known.
+fn load_answers(base: &Path) -> Result<Vec<(String, Expected)>, String> {
+    let path = with_suffix(base, ".toml");
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Could not read {}: {e}", path.display())),
+    };
+
+    let parse = || -> Result<_, String> {
+        let table = content.parse::<toml::Value>().map_err(|e| e.to_string())?;
+        let table = table.as_table().ok_or("Expected a table")?;
+        table
+            .iter()
+            .map(|(name, value)| Ok((name.clone(), answers::parse_parts(name, value)?)))
+            .collect()
+    };
+    parse().map_err(|e| format!("Invalid example answers in {}: {e}", path.display()))
+}
+
+fn with_suffix(base: &Path, suffix: &str) -> PathBuf {
+    let mut path = base.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
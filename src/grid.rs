@@ -0,0 +1,436 @@
+//! A dense 2D grid, as most of the map puzzles have them.
+//!
+//! Cells are stored row by row and addressed by their `(x, y)` position, with `x` the column
+//! and `y` the row, starting at the top left. As a [`PuzzleInput`], every byte of a line
+//! becomes one cell through its `TryFrom<u8>` impl, e.g. derived with `num_enum`.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{lines, ParseError, PuzzleInput};
+
+/// The `(x, y)` position of a cell.
+pub type Pos = (usize, usize);
+
+/// A step from one cell to the next, as `(dx, dy)`.
+pub type Dir = (isize, isize);
+
+pub const UP: Dir = (0, -1);
+pub const RIGHT: Dir = (1, 0);
+pub const DOWN: Dir = (0, 1);
+pub const LEFT: Dir = (-1, 0);
+
+/// The four straight directions, clockwise and starting up.
+pub const DIRS4: [Dir; 4] = [UP, RIGHT, DOWN, LEFT];
+
+/// The straight and the diagonal directions, clockwise and starting up.
+pub const DIRS8: [Dir; 8] = [UP, (1, -1), RIGHT, (1, 1), DOWN, (-1, 1), LEFT, (-1, -1)];
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    #[must_use]
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates every cell from its position, row by row.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// # Panics
+    /// When the cells do not fill complete rows of `width`.
+    #[must_use]
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} cells do not fit into rows of {width}",
+            cells.len()
+        );
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of cells.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    #[must_use]
+    pub const fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    #[must_use]
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.index_of(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            let index = self.index_of(pos);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// The index of `pos` in the cells, e.g. for a `Vec<bool>` of visited cells.
+    #[must_use]
+    pub const fn index_of(&self, (x, y): Pos) -> usize {
+        y * self.width + x
+    }
+
+    /// The position of the cell at `index`, the inverse of [`Grid::index_of`].
+    #[must_use]
+    pub const fn pos_of(&self, index: usize) -> Pos {
+        (index % self.width, index / self.width)
+    }
+
+    /// All cells, row by row.
+    #[must_use]
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    #[must_use]
+    pub fn into_cells(self) -> Vec<T> {
+        self.cells
+    }
+
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        // `chunks_exact` does not accept 0, which is only the width of a grid without cells.
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        (0..self.height).map(move |y| &self[(x, y)])
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row by row, that matches `pred`.
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.cells.iter().position(pred).map(|i| self.pos_of(i))
+    }
+
+    /// The neighbor of `pos` in direction `dir`, if it is inside of the grid.
+    #[must_use]
+    pub fn step(&self, (x, y): Pos, (dx, dy): Dir) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The straight neighbors of `pos` that are inside of the grid, see [`DIRS4`].
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS4.into_iter().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The straight and diagonal neighbors of `pos` that are inside of the grid, see [`DIRS8`].
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS8.into_iter().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The positions from `pos` in direction `dir` up to the edge, without `pos` itself.
+    /// A diagonal direction gives a diagonal ray.
+    pub fn ray(&self, pos: Pos, dir: Dir) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(pos, dir), move |&pos| self.step(pos, dir))
+    }
+
+    #[must_use]
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(&mut f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Mirrors the grid at its main diagonal, rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Rotates the grid clockwise by a quarter turn.
+    #[must_use]
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        Self::from_fn(height, self.width, |(x, y)| {
+            self[(y, height - 1 - x)].clone()
+        })
+    }
+
+    /// Rotates the grid counterclockwise by a quarter turn.
+    #[must_use]
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        Self::from_fn(self.height, width, |(x, y)| {
+            self[(width - 1 - y, x)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    #[must_use]
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        Self::from_fn(width, self.height, |(x, y)| {
+            self[(width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    #[must_use]
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        Self::from_fn(self.width, height, |(x, y)| {
+            self[(x, height - 1 - y)].clone()
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{pos:?} is outside of the grid");
+        &self.cells[self.index_of(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{pos:?} is outside of the grid");
+        let index = self.index_of(pos);
+        &mut self.cells[index]
+    }
+}
+
+/// One line per row, without separators between the cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                cell.fmt(f)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: TryFrom<u8>> PuzzleInput for Grid<T> {
+    type Out<'a> = Self;
+
+    fn from_input(input: &str) -> Self::Out<'_> {
+        Self::try_from_input(input).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_from_input(input: &str) -> Result<Self::Out<'_>, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for line in lines(input) {
+            let expected = *width.get_or_insert(line.len());
+            if line.len() != expected {
+                return Err(ParseError::new(
+                    format!("Expected a row of {expected} cells, got {}", line.len()),
+                    line,
+                ));
+            }
+            for (i, byte) in line.bytes().enumerate() {
+                let cell = T::try_from(byte)
+                    .map_err(|_| ParseError::bytes("Invalid cell", &line.as_bytes()[i..]))?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or_default(),
+            height,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<u8> for Tile {
+        type Error = u8;
+
+        fn try_from(byte: u8) -> Result<Self, Self::Error> {
+            match byte {
+                b'.' => Ok(Self::Open),
+                b'#' => Ok(Self::Wall),
+                _ => Err(byte),
+            }
+        }
+    }
+
+    impl Display for Tile {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(match self {
+                Self::Open => ".",
+                Self::Wall => "#",
+            })
+        }
+    }
+
+    fn chars(grid: &Grid<u8>) -> String {
+        grid.map(|&b| char::from(b)).to_string()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::<Tile>::from_input("#..\n.#.\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(0, 0)], Tile::Wall);
+        assert_eq!(grid[(1, 1)], Tile::Wall);
+        assert_eq!(grid.get((2, 1)), Some(&Tile::Open));
+        assert_eq!(grid.get((3, 1)), None);
+        assert_eq!(grid.to_string(), "#..\n.#.\n");
+        assert_eq!(grid.position(|&t| t == Tile::Wall), Some((0, 0)));
+
+        let error = Grid::<Tile>::try_from_input("#..\n.x.\n").unwrap_err();
+        assert!(error.to_string().contains("Invalid cell"), "{error}");
+        assert!(Grid::<Tile>::try_from_input("#..\n.#\n").is_err());
+        assert!(Grid::<Tile>::try_from_input("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 2, 0_u8);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbors4((1, 1)).collect::<Vec<_>>(),
+            [(1, 0), (2, 1), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbors8((2, 0)).collect::<Vec<_>>(),
+            [(2, 1), (1, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_rays() {
+        let grid = Grid::<u8>::from_input("abc\ndef\nghi\n");
+        let cells = |ray: &mut dyn Iterator<Item = Pos>| {
+            ray.map(|pos| char::from(grid[pos])).collect::<String>()
+        };
+        assert_eq!(cells(&mut grid.ray((0, 0), RIGHT)), "bc");
+        assert_eq!(cells(&mut grid.ray((0, 0), (1, 1))), "ei");
+        assert_eq!(cells(&mut grid.ray((1, 2), UP)), "eb");
+        assert_eq!(cells(&mut grid.ray((2, 2), RIGHT)), "");
+        assert_eq!(grid.row(1), b"def");
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), b"cfi");
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = Grid::<u8>::from_input("abc\ndef\n");
+        assert_eq!(chars(&grid.transpose()), "ad\nbe\ncf\n");
+        assert_eq!(chars(&grid.rotate_right()), "da\neb\nfc\n");
+        assert_eq!(chars(&grid.rotate_left()), "cf\nbe\nad\n");
+        assert_eq!(chars(&grid.flip_horizontal()), "cba\nfed\n");
+        assert_eq!(chars(&grid.flip_vertical()), "def\nabc\n");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+}
@@ -0,0 +1,334 @@
+//! Answers that were already submitted, stored per year in `.guesses/<year>.toml`,
+//! so that `aoc submit` never sends the same answer twice.
+//!
+//! ```toml
+//! [day5.part1]
+//! "1337" = "too high"
+//! "42" = "too low"
+//! ```
+//!
+//! A numeric answer is also rejected when an earlier guess already shows that it is too high
+//! or too low. When the website asks to wait before the next answer of a day, the deadline is
+//! kept as Unix time, and nothing is submitted for that day until it has passed:
+//!
+//! ```toml
+//! [day5]
+//! wait_until = 1670217290
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    fmt, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::client::Outcome;
+
+/// Directory with the guess files, relative to the crate root.
+pub const DEFAULT_DIR: &str = ".guesses";
+
+/// What the website said about a submitted answer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Guess {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Guess {
+    /// `None` for the outcomes that did not check the answer.
+    #[must_use]
+    pub const fn from_outcome(outcome: Outcome) -> Option<Self> {
+        match outcome {
+            Outcome::Correct => Some(Self::Correct),
+            Outcome::TooHigh => Some(Self::TooHigh),
+            Outcome::TooLow => Some(Self::TooLow),
+            Outcome::Wrong => Some(Self::Wrong),
+            Outcome::Wait(_) | Outcome::WrongLevel => None,
+        }
+    }
+
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::Wrong => "wrong",
+        }
+    }
+}
+
+impl fmt::Display for Guess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Guess {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "too high" => Ok(Self::TooHigh),
+            "too low" => Ok(Self::TooLow),
+            "wrong" => Ok(Self::Wrong),
+            _ => Err(format!(
+                "Invalid guess `{s}`, expected correct, too high, too low or wrong"
+            )),
+        }
+    }
+}
+
+/// The submitted answers of one year.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Guesses {
+    parts: BTreeMap<(u8, u8), BTreeMap<String, Guess>>,
+    /// Per day, the Unix time in seconds until which the website does not accept answers.
+    waits: BTreeMap<u8, u64>,
+}
+
+impl Guesses {
+    /// The default location of the guesses of `year`.
+    #[must_use]
+    pub fn default_path(year: u16) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join(DEFAULT_DIR)
+            .join(format!("{year}.toml"))
+    }
+
+    /// Loads the guesses from `path`, a missing file means that nothing was submitted yet.
+    ///
+    /// # Errors
+    /// When the file cannot be read or is invalid.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content)
+                .map_err(|e| format!("Invalid guesses in {}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Could not read guesses {}: {e}", path.display())),
+        }
+    }
+
+    /// # Errors
+    /// When the file or its directory cannot be written.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let write = || {
+            std::fs::create_dir_all(path.parent().unwrap_or(path))?;
+            std::fs::write(path, self.to_toml())
+        };
+        write().map_err(|e| format!("Could not write guesses {}: {e}", path.display()))
+    }
+
+    /// # Errors
+    /// When `content` is not valid TOML or does not contain the expected tables.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let table = content.parse::<toml::Value>().map_err(|e| e.to_string())?;
+        let table = table.as_table().ok_or("Expected a table")?;
+
+        let mut parts = BTreeMap::new();
+        let mut waits = BTreeMap::new();
+        for (key, value) in table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| format!("Invalid day `{key}`, expected e.g. `day1`"))?;
+            let value = value
+                .as_table()
+                .ok_or_else(|| format!("Expected `{key}` to be a table"))?;
+
+            for (part, value) in value {
+                let part_number = match part.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    "wait_until" => {
+                        let until = value
+                            .as_integer()
+                            .and_then(|until| u64::try_from(until).ok())
+                            .ok_or_else(|| {
+                                format!("Expected `{key}.wait_until` to be a Unix time")
+                            })?;
+                        waits.insert(day, until);
+                        continue;
+                    }
+                    _ => {
+                        return Err(format!(
+                            "Invalid part `{key}.{part}`, expected part1, part2 or wait_until"
+                        ))
+                    }
+                };
+                let value = value
+                    .as_table()
+                    .ok_or_else(|| format!("Expected `{key}.{part}` to be a table"))?;
+
+                let mut guesses = BTreeMap::new();
+                for (answer, guess) in value {
+                    let guess = guess
+                        .as_str()
+                        .ok_or_else(|| format!("Expected `{key}.{part}` to contain strings"))?;
+                    guesses.insert(answer.clone(), guess.parse()?);
+                }
+                parts.insert((day, part_number), guesses);
+            }
+        }
+
+        Ok(Self { parts, waits })
+    }
+
+    /// Why `answer` must not be submitted, if it was already submitted or is known to be wrong.
+    #[must_use]
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Option<String> {
+        let answer = answer.trim();
+        let guesses = self.parts.get(&(day, part))?;
+        if let Some(guess) = guesses.get(answer) {
+            return Some(format!("{answer} was already submitted, it was {guess}"));
+        }
+
+        let number = answer.parse::<i128>().ok();
+        guesses.iter().find_map(|(guessed, guess)| {
+            let beyond = |bound: fn(i128, i128) -> bool| {
+                number
+                    .zip(guessed.parse().ok())
+                    .is_some_and(|(a, b)| bound(a, b))
+            };
+            match guess {
+                Guess::Correct => Some(format!("Already solved, the answer is {guessed}")),
+                Guess::TooHigh if beyond(|answer, guessed| answer >= guessed) => Some(format!(
+                    "{answer} is too high, {guessed} already was too high"
+                )),
+                Guess::TooLow if beyond(|answer, guessed| answer <= guessed) => Some(format!(
+                    "{answer} is too low, {guessed} already was too low"
+                )),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, guess: Guess) {
+        self.parts
+            .entry((day, part))
+            .or_default()
+            .insert(answer.trim().to_owned(), guess);
+    }
+
+    /// Remembers that the website does not accept answers for `day` before `until`.
+    pub fn wait(&mut self, day: u8, until: SystemTime) {
+        let until = until.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let until = until.as_secs() + u64::from(until.subsec_nanos() > 0);
+        self.waits.insert(day, until);
+    }
+
+    /// How long the website still does not accept answers for `day`, in whole seconds.
+    #[must_use]
+    pub fn wait_left(&self, day: u8, now: SystemTime) -> Option<Duration> {
+        let until = UNIX_EPOCH + Duration::from_secs(*self.waits.get(&day)?);
+        let left = until.duration_since(now).ok()?;
+        let left = Duration::from_secs(left.as_secs() + u64::from(left.subsec_nanos() > 0));
+        Some(left).filter(|left| !left.is_zero())
+    }
+
+    fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        let section = |toml: &mut String, header: String| {
+            if !toml.is_empty() {
+                toml.push('\n');
+            }
+            toml.push_str(&header);
+        };
+        let mut waits = self.waits.iter().peekable();
+        for (&(day, part), guesses) in &self.parts {
+            // The wait of a day comes before the guesses of its parts.
+            while let Some((&wait_day, until)) = waits.next_if(|(&wait_day, _)| wait_day <= day) {
+                section(
+                    &mut toml,
+                    format!("[day{wait_day}]\nwait_until = {until}\n"),
+                );
+            }
+            section(&mut toml, format!("[day{day}.part{part}]\n"));
+            for (answer, guess) in guesses {
+                // A TOML string is also a valid key, and takes care of the escaping.
+                let answer = toml::Value::String(answer.clone());
+                toml.push_str(&format!("{answer} = \"{guess}\"\n"));
+            }
+        }
+        for (day, until) in waits {
+            section(&mut toml, format!("[day{day}]\nwait_until = {until}\n"));
+        }
+        toml
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let mut guesses = Guesses::default();
+        guesses.record(5, 1, "100", Guess::TooHigh);
+        guesses.record(5, 1, "10", Guess::TooLow);
+        guesses.record(5, 2, "ABC", Guess::Wrong);
+
+        assert!(guesses.check(5, 1, "100").is_some());
+        assert!(guesses.check(5, 1, "150").is_some());
+        assert!(guesses.check(5, 1, "7").is_some());
+        assert!(guesses.check(5, 1, "50").is_none());
+        assert!(guesses.check(5, 2, " ABC\n").is_some());
+        assert!(guesses.check(5, 2, "ABD").is_none());
+        assert!(guesses.check(6, 1, "100").is_none());
+
+        guesses.record(5, 1, "50", Guess::Correct);
+        assert!(guesses.check(5, 1, "51").is_some());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut guesses = Guesses::default();
+        guesses.record(12, 1, "1337", Guess::TooHigh);
+        guesses.record(12, 1, "42", Guess::TooLow);
+        guesses.record(2, 2, "a \"b\"", Guess::Wrong);
+        guesses.wait(2, UNIX_EPOCH + Duration::from_secs(1_670_217_290));
+        guesses.wait(20, UNIX_EPOCH + Duration::from_millis(1_671_512_000_500));
+
+        let toml = guesses.to_toml();
+        assert_eq!(
+            toml,
+            r#"[day2]
+wait_until = 1670217290
+
+[day2.part2]
+"a \"b\"" = "wrong"
+
+[day12.part1]
+"1337" = "too high"
+"42" = "too low"
+
+[day20]
+wait_until = 1671512001
+"#
+        );
+        assert_eq!(Guesses::parse(&toml).unwrap(), guesses);
+        assert!(Guesses::parse("[day1.part1]\n\"1\" = \"maybe\"").is_err());
+        assert!(Guesses::parse("[day1]\nwait_until = \"soon\"").is_err());
+    }
+
+    #[test]
+    fn test_wait() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_670_217_000);
+        let mut guesses = Guesses::default();
+        assert_eq!(guesses.wait_left(5, now), None);
+
+        guesses.wait(5, now + Duration::from_secs(290));
+        assert_eq!(guesses.wait_left(5, now), Some(Duration::from_secs(290)));
+        assert_eq!(
+            guesses.wait_left(5, now + Duration::from_millis(100_500)),
+            Some(Duration::from_secs(190))
+        );
+        assert_eq!(guesses.wait_left(5, now + Duration::from_secs(290)), None);
+        assert_eq!(guesses.wait_left(5, now + Duration::from_secs(3600)), None);
+        assert_eq!(guesses.wait_left(6, now), None);
+    }
+}
@@ -0,0 +1,196 @@
+use std::{
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::{Mutex, RwLock},
+};
+
+/// Environment variable that points to a directory containing the puzzle inputs.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+static INPUT_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+static CACHE: Mutex<Vec<(PathBuf, &'static str)>> = Mutex::new(Vec::new());
+
+/// Where to read a puzzle input from, as given on the command line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    #[must_use]
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Reads the whole input. The content lives for the rest of the program,
+    /// same as an input that would have been embedded with `include_str!`.
+    ///
+    /// # Errors
+    /// When the file or stdin cannot be read.
+    pub fn read(&self) -> io::Result<&'static str> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().lock().read_to_string(&mut input)?;
+                Ok(Box::leak(input.into_boxed_str()))
+            }
+            Self::File(path) => read_cached(path),
+        }
+    }
+}
+
+/// Overrides the directory in which [`load`] looks for input files.
+/// Takes precedence over the [`INPUT_DIR_ENV`] environment variable.
+pub fn set_dir(dir: impl Into<PathBuf>) {
+    *INPUT_DIR.write().unwrap() = Some(dir.into());
+}
+
+/// Resolves and reads a puzzle input at runtime.
+///
+/// `file` is the path given to `register!`, relative to `source_file`,
+/// which in turn is relative to `manifest_dir`.
+/// If an input directory was configured, the file name of `file` is looked up
+/// in the subdirectory of the year instead, e.g. `<dir>/2022/day16.txt`.
+///
+/// # Errors
+/// When the resolved file cannot be read.
+pub fn load(manifest_dir: &str, source_file: &str, file: &str) -> io::Result<&'static str> {
+    read_cached(&resolve(manifest_dir, source_file, file))
+}
+
+/// Where `aoc fetch` stores the input of a day, same as the paths given to `register!`.
+#[must_use]
+pub fn repo_path(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("bin")
+        .join(format!("aoc{year}"))
+        .join("input")
+        .join(format!("day{day}.txt"))
+}
+
+fn resolve(manifest_dir: &str, source_file: &str, file: &str) -> PathBuf {
+    let input_dir = INPUT_DIR
+        .read()
+        .unwrap()
+        .clone()
+        .or_else(|| std::env::var_os(INPUT_DIR_ENV).map(PathBuf::from));
+
+    resolve_in(input_dir.as_deref(), manifest_dir, source_file, file)
+}
+
+fn resolve_in(
+    input_dir: Option<&Path>,
+    manifest_dir: &str,
+    source_file: &str,
+    file: &str,
+) -> PathBuf {
+    let source_dir = Path::new(source_file).parent();
+    match input_dir {
+        Some(dir) => {
+            // The days of a year live in `aocYYYY`, its inputs in `<dir>/YYYY`.
+            let year = source_dir
+                .and_then(Path::file_name)
+                .and_then(|name| name.to_str()?.strip_prefix("aoc"))
+                .filter(|year| !year.is_empty() && year.bytes().all(|b| b.is_ascii_digit()));
+            let file_name = Path::new(file).file_name().unwrap_or(file.as_ref());
+            year.map_or_else(|| dir.to_path_buf(), |year| dir.join(year))
+                .join(file_name)
+        }
+        None => source_dir.map_or_else(
+            || PathBuf::from(file),
+            |dir| Path::new(manifest_dir).join(dir).join(file),
+        ),
+    }
+}
+
+fn read_cached(path: &Path) -> io::Result<&'static str> {
+    let mut cache = CACHE.lock().unwrap();
+    if let Some((_, input)) = cache.iter().find(|(p, _)| p == path) {
+        return Ok(input);
+    }
+
+    let input = std::fs::read_to_string(path).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("Could not read input file {}: {e}", path.display()),
+        )
+    })?;
+    let input: &'static str = Box::leak(input.into_boxed_str());
+    cache.push((path.to_path_buf(), input));
+
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+    #[test]
+    fn test_repo_path() {
+        assert_eq!(
+            resolve_in(
+                None,
+                MANIFEST_DIR,
+                "src/bin/aoc2022/day16.rs",
+                "input/day16.txt"
+            ),
+            repo_path(2022, 16)
+        );
+        // The `aoc` binary includes the days of every year from its own directory.
+        assert_eq!(
+            resolve_in(
+                None,
+                MANIFEST_DIR,
+                "src/bin/aoc/../aoc2022/day16.rs",
+                "input/day16.txt"
+            ),
+            Path::new(MANIFEST_DIR).join("src/bin/aoc/../aoc2022/input/day16.txt")
+        );
+    }
+
+    #[test]
+    fn test_input_dir() {
+        let dir = Path::new("/inputs");
+        assert_eq!(
+            resolve_in(
+                Some(dir),
+                MANIFEST_DIR,
+                "src/bin/aoc2022/day16.rs",
+                "input/day16.txt"
+            ),
+            Path::new("/inputs/2022/day16.txt")
+        );
+        assert_eq!(
+            resolve_in(
+                Some(dir),
+                MANIFEST_DIR,
+                "src/bin/aoc/../aoc2021/day16.rs",
+                "input/day16.txt"
+            ),
+            Path::new("/inputs/2021/day16.txt")
+        );
+        // Without a year to go by, the file is looked up in the directory itself.
+        assert_eq!(
+            resolve_in(Some(dir), MANIFEST_DIR, "src/day16.rs", "input/day16.txt"),
+            Path::new("/inputs/day16.txt")
+        );
+    }
+
+    #[test]
+    fn test_source_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("input/day16.txt"),
+            Source::File(PathBuf::from("input/day16.txt"))
+        );
+        assert_eq!(Source::from_arg("./-"), Source::File(PathBuf::from("./-")));
+    }
+}
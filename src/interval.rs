@@ -0,0 +1,325 @@
+//! Sets of integers, stored as sorted inclusive ranges.
+//!
+//! Inserted ranges that overlap or touch are merged, so `1..=3` and `4..=6` become `1..=6`.
+
+use std::{fmt::Debug, ops::RangeInclusive};
+
+/// Integers that can be stored in an [`IntervalSet`].
+pub trait Integer: Copy + Ord + Debug {
+    /// The next greater value, `None` at the maximum.
+    fn next(self) -> Option<Self>;
+    /// The next smaller value, `None` at the minimum.
+    fn prev(self) -> Option<Self>;
+    /// The value as an `i128`, which holds every value of the 64 bit types.
+    fn widen(self) -> i128;
+}
+
+macro_rules! def_integer {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl Integer for $t {
+                fn next(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn prev(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn widen(self) -> i128 {
+                    i128::from(self)
+                }
+            }
+        )+
+    };
+}
+
+def_integer!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl Integer for usize {
+    fn next(self) -> Option<Self> {
+        self.checked_add(1)
+    }
+
+    fn prev(self) -> Option<Self> {
+        self.checked_sub(1)
+    }
+
+    fn widen(self) -> i128 {
+        self as i128
+    }
+}
+
+impl Integer for isize {
+    fn next(self) -> Option<Self> {
+        self.checked_add(1)
+    }
+
+    fn prev(self) -> Option<Self> {
+        self.checked_sub(1)
+    }
+
+    fn widen(self) -> i128 {
+        self as i128
+    }
+}
+
+/// The number of values in `range`, 0 when it is empty.
+///
+/// Unlike `ExactSizeIterator::len`, this also works for the ranges of `i64` and `u64`,
+/// including `i64::MIN..=i64::MAX`.
+#[must_use]
+pub fn len<T: Integer>(range: &RangeInclusive<T>) -> u128 {
+    let len = range.end().widen() - range.start().widen() + 1;
+    u128::try_from(len).unwrap_or_default()
+}
+
+/// Whether `left` ends right before `right` starts, or overlaps with it.
+fn touches<T: Integer>(left: &RangeInclusive<T>, right: &RangeInclusive<T>) -> bool {
+    left.end().widen() + 1 >= right.start().widen()
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// Sorted, non-empty, and neither overlapping nor touching.
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// The disjoint ranges of the set, in ascending order.
+    #[must_use]
+    pub fn ranges(&self) -> &[RangeInclusive<T>] {
+        &self.ranges
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Removes all ranges, but keeps the allocated memory for reuse.
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+    }
+
+    /// The number of values in the set.
+    #[must_use]
+    pub fn covered_len(&self) -> u128 {
+        self.ranges.iter().map(len).sum()
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| *range.end() < value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| *range.start() <= value)
+    }
+
+    /// Whether every value of `range` is in the set, which is always the case for an empty range.
+    #[must_use]
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let index = self.ranges.partition_point(|r| r.end() < range.start());
+        self.ranges
+            .get(index)
+            .is_some_and(|r| r.start() <= range.start() && range.end() <= r.end())
+    }
+
+    /// The smallest value of `bound` that is not in the set.
+    #[must_use]
+    pub fn first_gap(&self, bound: RangeInclusive<T>) -> Option<T> {
+        let (start, end) = bound.into_inner();
+        let index = self.ranges.partition_point(|range| *range.end() < start);
+        let gap = match self.ranges.get(index) {
+            // Ranges never touch, so the value after this one is not in the set.
+            Some(range) if *range.start() <= start => range.end().next()?,
+            _ => start,
+        };
+        (gap <= end).then_some(gap)
+    }
+
+    /// Adds all values of `range`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| !touches(r, &range));
+        let last = self.ranges.partition_point(|r| touches(&range, r));
+
+        let (mut start, mut end) = range.into_inner();
+        if first < last {
+            start = start.min(*self.ranges[first].start());
+            end = end.max(*self.ranges[last - 1].end());
+        }
+        self.ranges.splice(first..last, Some(start..=end));
+    }
+
+    /// Removes all values of `range`, splitting the ranges it only partially covers.
+    pub fn remove(&mut self, range: &RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end() < range.start());
+        let last = self.ranges.partition_point(|r| r.start() <= range.end());
+        if first >= last {
+            return;
+        }
+
+        let before = range
+            .start()
+            .prev()
+            .map(|end| *self.ranges[first].start()..=end)
+            .filter(|before| !before.is_empty());
+        let after = range
+            .end()
+            .next()
+            .map(|start| start..=*self.ranges[last - 1].end())
+            .filter(|after| !after.is_empty());
+        self.ranges
+            .splice(first..last, before.into_iter().chain(after));
+    }
+
+    /// The values that are in `self`, in `other`, or in both.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.ranges.iter().cloned());
+        union
+    }
+
+    /// The values that are in both `self` and `other`.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(&a), Some(&b)) = (left.peek(), right.peek()) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+            // The range that ends first cannot overlap with anything else.
+            if a.end() < b.end() {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values that are in `self`, but not in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in &other.ranges {
+            difference.remove(range);
+        }
+        difference
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn single<T: Integer>(range: RangeInclusive<T>) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut set = [5..=7, 1..=2, 10..=12]
+            .into_iter()
+            .collect::<IntervalSet<i32>>();
+        assert_eq!(set.ranges(), [1..=2, 5..=7, 10..=12]);
+
+        set.insert(3..=4);
+        assert_eq!(set.ranges(), [1..=7, 10..=12]);
+        set.insert(6..=11);
+        assert_eq!(set, single(1..=12));
+        set.insert(RangeInclusive::new(20, 19));
+        assert_eq!(set, single(1..=12));
+        set.insert(-3..=-1);
+        assert_eq!(set.ranges(), [-3..=-1, 1..=12]);
+        assert_eq!(set.covered_len(), 15);
+
+        let mut set = IntervalSet::new();
+        set.insert(u8::MIN..=3);
+        set.insert(250..=u8::MAX);
+        set.insert(4..=249);
+        assert_eq!(set, single(0..=255));
+    }
+
+    #[test]
+    fn test_queries() {
+        let set = [-5..=0, 3..=8].into_iter().collect::<IntervalSet<i64>>();
+        assert!(set.contains(-5) && set.contains(0) && set.contains(8));
+        assert!(!set.contains(1) && !set.contains(9) && !set.contains(-6));
+        assert!(set.contains_range(&(4..=8)));
+        assert!(!set.contains_range(&(0..=3)));
+
+        assert_eq!(set.first_gap(-5..=20), Some(1));
+        assert_eq!(set.first_gap(3..=20), Some(9));
+        assert_eq!(set.first_gap(-10..=20), Some(-10));
+        assert_eq!(set.first_gap(3..=8), None);
+
+        let full = single(i64::MIN..=i64::MAX);
+        assert_eq!(full.covered_len(), 1 << 64);
+        assert_eq!(full.first_gap(0..=10), None);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = [1..=5, 10..=15].into_iter().collect::<IntervalSet<u32>>();
+        let b = [4..=11, 20..=20].into_iter().collect::<IntervalSet<u32>>();
+
+        assert_eq!(a.union(&b).ranges(), [1..=15, 20..=20]);
+        assert_eq!(a.intersection(&b).ranges(), [4..=5, 10..=11]);
+        assert_eq!(a.difference(&b).ranges(), [1..=3, 12..=15]);
+        assert_eq!(b.difference(&a).ranges(), [6..=9, 20..=20]);
+        assert!(a.difference(&a).is_empty());
+
+        let mut c = a;
+        c.remove(&(0..=u32::MAX));
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(len(&(3..=3)), 1);
+        assert_eq!(len(&(-2_i64..=2)), 5);
+        assert_eq!(len(&RangeInclusive::new(3, 2)), 0);
+        assert_eq!(len(&(i64::MIN..=i64::MAX)), 1 << 64);
+        assert_eq!(len(&(0..=u64::MAX)), 1 << 64);
+    }
+}
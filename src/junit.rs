@@ -0,0 +1,178 @@
+//! JUnit XML reports of checked answers, so that CI can show the result of every part.
+
+use std::{
+    fmt::{self, Write as _},
+    io::{self, Write},
+    time::Duration,
+};
+
+use crate::answers::Verdict;
+
+struct TestCase {
+    year: u16,
+    day: u8,
+    part: u8,
+    time: Duration,
+    answer: String,
+    verdict: Verdict,
+}
+
+/// Collects the checked parts of a run, one test case per part and one suite per year.
+#[derive(Default)]
+pub struct JUnit {
+    cases: Vec<TestCase>,
+}
+
+impl JUnit {
+    pub fn add(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: u8,
+        time: Duration,
+        answer: &str,
+        verdict: Verdict,
+    ) {
+        self.cases.push(TestCase {
+            year,
+            day,
+            part,
+            time,
+            answer: answer.to_owned(),
+            verdict,
+        });
+    }
+
+    /// # Errors
+    /// When writing fails.
+    pub fn write(&self, mut out: impl Write) -> io::Result<()> {
+        let count = |cases: &[&TestCase], verdict: fn(&Verdict) -> bool| {
+            cases.iter().filter(|case| verdict(&case.verdict)).count()
+        };
+        let all = self.cases.iter().collect::<Vec<_>>();
+
+        writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            out,
+            r#"<testsuites name="aoc" tests="{}" failures="{}" skipped="{}" time="{}">"#,
+            all.len(),
+            count(&all, Verdict::is_wrong),
+            count(&all, |v| *v == Verdict::Unknown),
+            seconds(self.cases.iter().map(|case| case.time).sum()),
+        )?;
+
+        let mut years = self.cases.iter().map(|case| case.year).collect::<Vec<_>>();
+        years.dedup();
+        for year in years {
+            let cases = self
+                .cases
+                .iter()
+                .filter(|case| case.year == year)
+                .collect::<Vec<_>>();
+
+            writeln!(
+                out,
+                r#"  <testsuite name="aoc{year}" tests="{}" failures="{}" skipped="{}" time="{}">"#,
+                cases.len(),
+                count(&cases, Verdict::is_wrong),
+                count(&cases, |v| *v == Verdict::Unknown),
+                seconds(cases.iter().map(|case| case.time).sum()),
+            )?;
+            for case in cases {
+                write!(
+                    out,
+                    r#"    <testcase classname="aoc{year}.day{:02}" name="part{}" time="{}""#,
+                    case.day,
+                    case.part,
+                    seconds(case.time)
+                )?;
+                match &case.verdict {
+                    Verdict::Correct => writeln!(out, "/>")?,
+                    Verdict::Wrong { expected } => {
+                        writeln!(out, ">")?;
+                        writeln!(
+                            out,
+                            r#"      <failure message="wrong answer">expected: {}&#10;actual: {}</failure>"#,
+                            Escaped(expected),
+                            Escaped(&case.answer),
+                        )?;
+                        writeln!(out, "    </testcase>")?;
+                    }
+                    Verdict::Unknown => {
+                        writeln!(out, ">")?;
+                        writeln!(out, r#"      <skipped message="no known answer"/>"#)?;
+                        writeln!(out, "    </testcase>")?;
+                    }
+                }
+            }
+            writeln!(out, "  </testsuite>")?;
+        }
+
+        writeln!(out, "</testsuites>")
+    }
+}
+
+fn seconds(time: Duration) -> String {
+    format!("{:.6}", time.as_secs_f64())
+}
+
+/// Text content or attribute value with the XML special characters escaped.
+struct Escaped<'a>(&'a str);
+
+impl fmt::Display for Escaped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\n' => f.write_str("&#10;")?,
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let mut junit = JUnit::default();
+        let time = Duration::from_micros(1500);
+        junit.add(2022, 1, 1, time, "42", Verdict::Correct);
+        junit.add(
+            2022,
+            1,
+            2,
+            time,
+            "<1>",
+            Verdict::Wrong {
+                expected: String::from("a\nb"),
+            },
+        );
+        junit.add(2022, 2, 1, time, "7", Verdict::Unknown);
+
+        let mut out = Vec::new();
+        junit.write(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="aoc" tests="3" failures="1" skipped="1" time="0.004500">
+  <testsuite name="aoc2022" tests="3" failures="1" skipped="1" time="0.004500">
+    <testcase classname="aoc2022.day01" name="part1" time="0.001500"/>
+    <testcase classname="aoc2022.day01" name="part2" time="0.001500">
+      <failure message="wrong answer">expected: a&#10;b&#10;actual: &lt;1&gt;</failure>
+    </testcase>
+    <testcase classname="aoc2022.day02" name="part1" time="0.001500">
+      <skipped message="no known answer"/>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+}
@@ -6,6 +6,27 @@ use std::{
     time::{Duration, Instant},
 };
 
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod client;
+mod error;
+pub mod examples;
+pub mod grid;
+pub mod guesses;
+pub mod input;
+pub mod interval;
+pub mod junit;
+pub mod point;
+pub mod readme;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+
+pub use error::{try_parse, ParseError};
+
 #[macro_export]
 macro_rules! poop {
     ($($arg:tt)*) => {
@@ -27,6 +48,12 @@ trait MaxDefault {
     fn max_default() -> Self;
 }
 
+/// The smaller and the greater of two values, component-wise for points.
+trait Bounded {
+    fn lower(self, other: Self) -> Self;
+    fn upper(self, other: Self) -> Self;
+}
+
 macro_rules! def_impl {
     ($($t:ty),+ $(,)?) => {
         $(
@@ -42,6 +69,16 @@ macro_rules! def_impl {
                 }
             }
 
+            impl Bounded for $t {
+                fn lower(self, other: Self) -> Self {
+                    self.min(other)
+                }
+
+                fn upper(self, other: Self) -> Self {
+                    self.max(other)
+                }
+            }
+
         )+
     };
 }
@@ -63,11 +100,11 @@ impl<T: MinDefault + MaxDefault> Default for MinMax<T> {
     }
 }
 
-impl<A: Copy + Ord + MinDefault + MaxDefault> FromIterator<A> for MinMax<A> {
+impl<A: Copy + Bounded + MinDefault + MaxDefault> FromIterator<A> for MinMax<A> {
     fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
         iter.into_iter().fold(MinMax::default(), |mut mn, x| {
-            mn.max = mn.max.max(x);
-            mn.min = mn.min.min(x);
+            mn.max = mn.max.upper(x);
+            mn.min = mn.min.lower(x);
             mn
         })
     }
@@ -114,6 +151,14 @@ where
 pub trait Parse {
     type Out<'a>;
     fn parse_from(input: &str) -> Self::Out<'_>;
+
+    /// Fallible version of [`Parse::parse_from`], defaults to the infallible one.
+    ///
+    /// # Errors
+    /// When the input is invalid.
+    fn try_parse_from(input: &str) -> Result<Self::Out<'_>, ParseError> {
+        Ok(Self::parse_from(input))
+    }
 }
 
 impl Parse for [u8] {
@@ -139,6 +184,14 @@ where
     type Out<'a>;
 
     fn from_input(input: &str) -> Self::Out<'_>;
+
+    /// Fallible version of [`PuzzleInput::from_input`], defaults to the infallible one.
+    ///
+    /// # Errors
+    /// When the input is invalid.
+    fn try_from_input(input: &str) -> Result<Self::Out<'_>, ParseError> {
+        Ok(Self::from_input(input))
+    }
 }
 
 pub trait ProcessInput {
@@ -146,6 +199,14 @@ pub trait ProcessInput {
     type Out<'a>;
 
     fn process(input: <Self::In as PuzzleInput>::Out<'_>) -> Self::Out<'_>;
+
+    /// Fallible version of [`ProcessInput::process`], defaults to the infallible one.
+    ///
+    /// # Errors
+    /// When the input is invalid.
+    fn try_process(input: <Self::In as PuzzleInput>::Out<'_>) -> Result<Self::Out<'_>, ParseError> {
+        Ok(Self::process(input))
+    }
 }
 
 impl PuzzleInput for () {
@@ -165,6 +226,10 @@ where
     fn from_input(input: &str) -> Self::Out<'_> {
         input.split("\n\n").map(|l| T::from_input(l)).collect()
     }
+
+    fn try_from_input(input: &str) -> Result<Self::Out<'_>, ParseError> {
+        input.split("\n\n").map(|l| T::try_from_input(l)).collect()
+    }
 }
 
 pub struct StdFromStrParsing<T>(PhantomData<T>);
@@ -172,13 +237,17 @@ pub struct StdFromStrParsing<T>(PhantomData<T>);
 impl<T> PuzzleInput for StdFromStrParsing<T>
 where
     T: FromStr,
-    <T as FromStr>::Err: Debug,
+    <T as FromStr>::Err: Debug + Display,
 {
     type Out<'a> = Vec<T>;
 
     fn from_input(input: &str) -> Self::Out<'_> {
         lines(input).map(|l| T::from_str(l).unwrap()).collect()
     }
+
+    fn try_from_input(input: &str) -> Result<Self::Out<'_>, ParseError> {
+        lines(input).map(try_parse).collect()
+    }
 }
 
 pub struct Parsing<T: ?Sized>(PhantomData<T>);
@@ -192,6 +261,10 @@ where
     fn from_input(input: &str) -> Self::Out<'_> {
         lines(input).map(|l| T::parse_from(l)).collect()
     }
+
+    fn try_from_input(input: &str) -> Result<Self::Out<'_>, ParseError> {
+        lines(input).map(|l| T::try_parse_from(l)).collect()
+    }
 }
 
 pub struct Post<T>(PhantomData<T>);
@@ -206,6 +279,11 @@ where
         let input = T::In::from_input(input);
         T::process(input)
     }
+
+    fn try_from_input(input: &str) -> Result<Self::Out<'_>, ParseError> {
+        let input = T::In::try_from_input(input)?;
+        T::try_process(input)
+    }
 }
 
 pub struct First<T>(PhantomData<T>);
@@ -222,12 +300,19 @@ where
     fn process(input: <T as PuzzleInput>::Out<'_>) -> Self::Out<'_> {
         <T::Out<'_> as PopFirst>::pop_first(input)
     }
+
+    fn try_process(input: <T as PuzzleInput>::Out<'_>) -> Result<Self::Out<'_>, ParseError> {
+        <T::Out<'_> as PopFirst>::try_pop_first(input)
+            .ok_or_else(|| ParseError::without_location("Expected at least one item"))
+    }
 }
 
 pub trait PopFirst {
     type Out;
 
     fn pop_first(self) -> Self::Out;
+
+    fn try_pop_first(self) -> Option<Self::Out>;
 }
 
 impl<T> PopFirst for Vec<T> {
@@ -236,42 +321,192 @@ impl<T> PopFirst for Vec<T> {
     fn pop_first(self) -> Self::Out {
         self.into_iter().next().unwrap()
     }
+
+    fn try_pop_first(self) -> Option<Self::Out> {
+        self.into_iter().next()
+    }
 }
 
 pub fn lines(s: &str) -> impl Iterator<Item = &str> + '_ {
     s.lines().map(str::trim).filter(|line| !line.is_empty())
 }
 
+/// Every pair of two different items, in the order of `items`.
+pub fn pairs<T>(items: &[T]) -> impl Iterator<Item = [&T; 2]> + '_ {
+    items
+        .iter()
+        .enumerate()
+        .flat_map(move |(i, a)| items[i + 1..].iter().map(move |b| [a, b]))
+}
+
+/// Every pair of two different items in both orders, `[a, b]` and `[b, a]`.
+pub fn ordered_pairs<T>(items: &[T]) -> impl Iterator<Item = [&T; 2]> + '_ {
+    pairs(items).flat_map(|[a, b]| [[a, b], [b, a]])
+}
+
+#[derive(Clone, Debug, Default)]
 pub struct PuzzleSolution<T> {
     pub part1: T,
     pub part2: T,
     pub parse_time: Duration,
     pub part1_time: Duration,
     pub part2_time: Duration,
+    pub parse_allocs: alloc::Allocs,
+    pub part1_allocs: alloc::Allocs,
+    pub part2_allocs: alloc::Allocs,
+}
+
+impl<T> PuzzleSolution<T> {
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> PuzzleSolution<U> {
+        PuzzleSolution {
+            part1: f(self.part1),
+            part2: f(self.part2),
+            parse_time: self.parse_time,
+            part1_time: self.part1_time,
+            part2_time: self.part2_time,
+            parse_allocs: self.parse_allocs,
+            part1_allocs: self.part1_allocs,
+            part2_allocs: self.part2_allocs,
+        }
+    }
+}
+
+impl<T> PuzzleSolution<Option<T>> {
+    /// The time of parsing and of the reported parts. Part 1 also runs when only part 2 is
+    /// selected, as some days continue from its state, but its time is not counted then.
+    #[must_use]
+    pub fn total_time(&self) -> Duration {
+        let time = |answer: &Option<T>, time| {
+            if answer.is_some() {
+                time
+            } else {
+                Duration::ZERO
+            }
+        };
+        self.parse_time + time(&self.part1, self.part1_time) + time(&self.part2, self.part2_time)
+    }
+}
+
+/// Which parts of a day should be reported.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Parts {
+    pub part1: bool,
+    pub part2: bool,
+}
+
+impl Parts {
+    pub const BOTH: Self = Self::new(true, true);
+    pub const NONE: Self = Self::new(false, false);
+
+    #[must_use]
+    pub const fn new(part1: bool, part2: bool) -> Self {
+        Self { part1, part2 }
+    }
+
+    /// `None` if `part` is neither 1 nor 2.
+    #[must_use]
+    pub const fn only(part: u8) -> Option<Self> {
+        match part {
+            1 => Some(Self::new(true, false)),
+            2 => Some(Self::new(false, true)),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self::new(self.part1 || other.part1, self.part2 || other.part2)
+    }
+
+    #[must_use]
+    pub const fn without(self, other: Self) -> Self {
+        Self::new(self.part1 && !other.part1, self.part2 && !other.part2)
+    }
+
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        !self.part1 && !self.part2
+    }
+}
+
+impl Default for Parts {
+    fn default() -> Self {
+        Self::BOTH
+    }
 }
 
 pub trait Solution {
     type Input: PuzzleInput;
     type Output;
 
-    fn puzzle_input() -> &'static str;
+    fn try_puzzle_input() -> std::io::Result<&'static str>;
+
+    fn puzzle_input() -> &'static str {
+        Self::try_puzzle_input().unwrap_or_else(|e| panic!("{e}"))
+    }
 
     #[inline]
     fn parse_input(input: &str) -> <Self::Input as PuzzleInput>::Out<'_> {
         <Self::Input as PuzzleInput>::from_input(input)
     }
 
+    /// Parses through the fallible path and locates any error within `input`.
+    ///
+    /// # Errors
+    /// When the input is invalid.
+    #[inline]
+    fn try_parse_input(input: &str) -> Result<<Self::Input as PuzzleInput>::Out<'_>, ParseError> {
+        <Self::Input as PuzzleInput>::try_from_input(input).map_err(|e| e.locate(input))
+    }
+
     fn run(
         input: <Self::Input as PuzzleInput>::Out<'_>,
         parse_time: Duration,
-    ) -> PuzzleSolution<Self::Output>;
+    ) -> PuzzleSolution<Self::Output> {
+        Self::run_parts(input, parse_time, Parts::BOTH)
+            .map(|answer| answer.expect("both parts are selected"))
+    }
+
+    /// Runs the selected parts, unselected answers are `None`.
+    /// Part 1 always runs, since some days continue from its state in part 2.
+    /// The allocations of parsing are left empty for the caller that measured the parsing.
+    fn run_parts(
+        input: <Self::Input as PuzzleInput>::Out<'_>,
+        parse_time: Duration,
+        parts: Parts,
+    ) -> PuzzleSolution<Option<Self::Output>>;
 
     fn solve() -> PuzzleSolution<Self::Output> {
-        let input = Self::puzzle_input();
+        Self::solve_on(Self::puzzle_input())
+    }
+
+    fn solve_on(input: &str) -> PuzzleSolution<Self::Output> {
+        let counter = alloc::Counter::start();
         let start = Instant::now();
         let input = Self::parse_input(input);
         let parse_time = start.elapsed();
-        Self::run(input, parse_time)
+        let parse_allocs = counter.stop();
+        PuzzleSolution {
+            parse_allocs,
+            ..Self::run(input, parse_time)
+        }
+    }
+
+    /// # Errors
+    /// When the input is invalid.
+    fn try_solve_on(
+        input: &str,
+        parts: Parts,
+    ) -> Result<PuzzleSolution<Option<Self::Output>>, ParseError> {
+        let counter = alloc::Counter::start();
+        let start = Instant::now();
+        let input = Self::try_parse_input(input)?;
+        let parse_time = start.elapsed();
+        let parse_allocs = counter.stop();
+        Ok(PuzzleSolution {
+            parse_allocs,
+            ..Self::run_parts(input, parse_time, parts)
+        })
     }
 }
 
@@ -286,6 +521,12 @@ pub trait SolutionExt: Solution {
         let input = Self::puzzle_input();
         Self::run_on(input)
     }
+
+    fn try_run_on(input: &str) -> Result<(Self::Output, Self::Output), ParseError> {
+        let input = Self::try_parse_input(input)?;
+        let PuzzleSolution { part1, part2, .. } = Self::run(input, Duration::ZERO);
+        Ok((part1, part2))
+    }
 }
 
 impl<T: Solution> SolutionExt for T {}
@@ -294,6 +535,8 @@ pub struct ResultLine {
     prefix: String,
     duration: Duration,
     solution: Option<Box<dyn Display>>,
+    verdict: Option<answers::Verdict>,
+    allocs: Option<alloc::Allocs>,
 }
 
 impl ResultLine {
@@ -316,6 +559,26 @@ impl ResultLine {
             prefix,
             duration,
             solution,
+            verdict: None,
+            allocs: None,
+        }
+    }
+
+    /// Shows the allocations next to the duration.
+    #[must_use]
+    pub fn with_allocs(self, allocs: alloc::Allocs) -> Self {
+        Self {
+            allocs: Some(allocs),
+            ..self
+        }
+    }
+
+    /// Marks the solution as correct, wrong or unknown.
+    #[must_use]
+    pub fn with_verdict(self, verdict: answers::Verdict) -> Self {
+        Self {
+            verdict: Some(verdict),
+            ..self
         }
     }
 }
@@ -325,7 +588,11 @@ impl Display for ResultLine {
         use owo_colors::{OwoColorize, Stream::Stdout};
         const DEFAULT_WIDTH: usize = 42;
 
-        let duration = format!(" ({})", humantime::format_duration(self.duration));
+        let duration = humantime::format_duration(self.duration);
+        let duration = match self.allocs {
+            Some(allocs) => format!(" ({duration}, {allocs})"),
+            None => format!(" ({duration})"),
+        };
 
         write!(
             f,
@@ -358,6 +625,24 @@ impl Display for ResultLine {
                     .if_supports_color(Stdout, |t| t.bold())
             )?;
 
+            match &self.verdict {
+                Some(answers::Verdict::Correct) => {
+                    write!(f, " {}", "✓".if_supports_color(Stdout, |t| t.green()))?;
+                }
+                Some(answers::Verdict::Wrong { expected }) => {
+                    let wrong = if expected.contains('\n') {
+                        String::from("✗ wrong answer")
+                    } else {
+                        format!("✗ expected {expected}")
+                    };
+                    write!(f, " {}", wrong.if_supports_color(Stdout, |t| t.red()))?;
+                }
+                Some(answers::Verdict::Unknown) => {
+                    write!(f, " {}", "?".if_supports_color(Stdout, |t| t.yellow()))?;
+                }
+                None => {}
+            }
+
             for line in solution {
                 writeln!(f)?;
                 write!(
@@ -405,9 +690,26 @@ macro_rules! input {
     };
 }
 
+/// Registers the [`Solution`] of a day as `Solver`, and generates its tests and benchmarks.
+///
+/// ```ignore
+/// register!(
+///     "input/day14.txt";
+///     (mut input: input!(parse Input)) -> Output {
+///         part1(&mut input);
+///         part2(&mut input);
+///     }
+///     answers: 1330, 26139;
+/// );
+/// ```
+///
+/// The parts are expressions of the parsed `input`, part 2 runs after part 1 on the same input.
+/// `answers` generates a `test` on the puzzle input. The benchmarks of the parts run the same
+/// expressions, on a fresh clone of the input in each iteration if it is declared as `mut`.
+/// Other tests, like the [`examples!`], are written next to the day as usual.
 #[macro_export]
 macro_rules! register {
-    ($file:literal; ($input:ident: $input_ty:ty) -> $output_ty:ty { $part1:expr; $part2:expr $(;)? }) => {
+    (@solver $file:literal; ($input:ident: $input_ty:ty) -> $output_ty:ty { $part1:expr; $part2:expr }) => {
         #[allow(clippy::redundant_pub_crate)]
         pub(crate) struct Solver;
 
@@ -415,66 +717,179 @@ macro_rules! register {
             type Input = $input_ty;
             type Output = $output_ty;
 
+            #[cfg(feature = "embed-inputs")]
             #[inline]
-            fn puzzle_input() -> &'static str {
-                ::std::include_str!($file)
+            fn try_puzzle_input() -> ::std::io::Result<&'static str> {
+                Ok(::std::include_str!($file))
             }
 
+            #[cfg(not(feature = "embed-inputs"))]
             #[inline]
-            fn run(
+            fn try_puzzle_input() -> ::std::io::Result<&'static str> {
+                $crate::input::load(::std::env!("CARGO_MANIFEST_DIR"), ::std::file!(), $file)
+            }
+
+            #[inline]
+            fn run_parts(
                 mut $input: <$input_ty as $crate::PuzzleInput>::Out<'_>,
                 parse_time: ::std::time::Duration,
-            ) -> $crate::PuzzleSolution<Self::Output> {
+                parts: $crate::Parts,
+            ) -> $crate::PuzzleSolution<Option<Self::Output>> {
+                let counter = $crate::alloc::Counter::start();
                 let start = ::std::time::Instant::now();
                 let part1 = $part1;
                 let part1_time = start.elapsed();
+                let part1_allocs = counter.stop();
+                let counter = $crate::alloc::Counter::start();
                 let start = ::std::time::Instant::now();
-                let part2 = $part2;
+                let part2 = if parts.part2 { Some($part2) } else { None };
                 let part2_time = start.elapsed();
+                let part2_allocs = counter.stop();
 
                 $crate::PuzzleSolution {
-                    part1,
+                    part1: parts.part1.then_some(part1),
                     part2,
                     part1_time,
                     part2_time,
                     parse_time,
+                    part1_allocs,
+                    part2_allocs,
+                    parse_allocs: $crate::alloc::Allocs::default(),
+                }
+            }
+        }
+    };
+
+    (@tests $mode:ident; $input:ident; $part1:expr; $part2:expr $(; $answer1:expr, $answer2:expr)?) => {
+        #[cfg(test)]
+        mod solver_tests {
+            use super::*;
+            use $crate::{Solution as _, SolutionExt as _};
+
+            $(
+                #[test]
+                fn test() {
+                    let (res1, res2) = Solver::run_on_input();
+                    assert_eq!(res1, $answer1);
+                    assert_eq!(res2, $answer2);
                 }
+            )?
+
+            #[cfg(feature = "nightly")]
+            #[bench]
+            fn bench_parsing(b: &mut ::test::Bencher) {
+                let Ok(input) = Solver::try_puzzle_input() else { return };
+                b.bytes = input.len() as u64;
+                b.iter(|| Solver::parse_input(input));
             }
+
+            #[cfg(feature = "nightly")]
+            $crate::register!(@bench $mode; $input; $part1; $part2);
+        }
+    };
+
+    // A part does not have to use the input, e.g. when the last day has no second part.
+    // Without a puzzle input, e.g. before it is downloaded, the benchmarks do nothing.
+    (@bench borrowed; $input:ident; $part1:expr; $part2:expr) => {
+        #[bench]
+        #[allow(unused_variables)]
+        fn bench_pt1(b: &mut ::test::Bencher) {
+            let Ok(input) = Solver::try_puzzle_input() else { return };
+            let $input = Solver::parse_input(input);
+            b.iter(|| $part1);
+        }
+
+        #[bench]
+        #[allow(unused_variables)]
+        fn bench_pt2(b: &mut ::test::Bencher) {
+            let Ok(input) = Solver::try_puzzle_input() else { return };
+            let $input = Solver::parse_input(input);
+            b.iter(|| $part2);
+        }
+    };
+
+    (@bench cloned; $input:ident; $part1:expr; $part2:expr) => {
+        #[bench]
+        #[allow(unused_mut, unused_variables, clippy::redundant_clone)]
+        fn bench_pt1(b: &mut ::test::Bencher) {
+            let Ok(input) = Solver::try_puzzle_input() else { return };
+            let $input = Solver::parse_input(input);
+            b.iter(|| {
+                let mut $input = $input.clone();
+                $part1
+            });
+        }
+
+        #[bench]
+        #[allow(unused_mut, unused_variables, clippy::redundant_clone)]
+        fn bench_pt2(b: &mut ::test::Bencher) {
+            // Part 2 sees the input as part 1 left it, same as when solving.
+            let Ok(input) = Solver::try_puzzle_input() else { return };
+            let mut $input = Solver::parse_input(input);
+            let _ = $part1;
+            b.iter(|| {
+                let mut $input = $input.clone();
+                $part2
+            });
         }
     };
+
+    (
+        $file:literal;
+        (mut $input:ident: $input_ty:ty) -> $output_ty:ty { $part1:expr; $part2:expr $(;)? }
+        $(answers: $answer1:expr, $answer2:expr $(;)?)?
+    ) => {
+        $crate::register!(@solver $file; ($input: $input_ty) -> $output_ty { $part1; $part2 });
+        $crate::register!(@tests cloned; $input; $part1; $part2 $(; $answer1, $answer2)?);
+    };
+
+    (
+        $file:literal;
+        ($input:ident: $input_ty:ty) -> $output_ty:ty { $part1:expr; $part2:expr $(;)? }
+        $(answers: $answer1:expr, $answer2:expr $(;)?)?
+    ) => {
+        $crate::register!(@solver $file; ($input: $input_ty) -> $output_ty { $part1; $part2 });
+        $crate::register!(@tests borrowed; $input; $part1; $part2 $(; $answer1, $answer2)?);
+    };
 }
 
+/// Turns the examples of a day into tests, each example into its own test case.
+/// `examples!("examples/day14"; ex1, ex2);` in the tests of a day checks
+/// `examples/day14.ex1.txt` and `examples/day14.ex2.txt` against `examples/day14.toml`.
 #[macro_export]
-macro_rules! aoc_main {
-    ($($day:literal => $md:ident),+ $(,)?) => {
-        use ::aoc::{PuzzleSolution, Solution};
-        $(mod $md);+;
+macro_rules! examples {
+    ($path:literal; $($example:ident),+ $(,)?) => {
+        $(
+            #[test]
+            fn $example() {
+                $crate::examples::test::<Solver>(
+                    ::std::env!("CARGO_MANIFEST_DIR"),
+                    ::std::file!(),
+                    $path,
+                    ::std::stringify!($example),
+                );
+            }
+        )+
+    };
+}
 
+#[macro_export]
+macro_rules! aoc_year {
+    ($year:literal; $($day:literal => $md:ident),+ $(,)?) => {
+        $(mod $md;)+
+
+        pub const YEAR: $crate::runner::Year = $crate::runner::Year {
+            year: $year,
+            days: &[$($crate::runner::Day::new::<$md::Solver>($day)),+],
+        };
+    };
+}
+
+#[macro_export]
+macro_rules! aoc_main {
+    ($($year:ident),+ $(,)?) => {
         fn main() {
-            let mut total_time = ::std::time::Duration::ZERO;
-            ::std::env::args()
-                .skip(1)
-                .flat_map(|s| s.parse::<u8>())
-                .for_each(|day| match day {
-                    $(
-                        $day => {
-                            let solution = $md::Solver::solve();
-                            let day_time = solution.parse_time + solution.part1_time + solution.part2_time;
-                            total_time += day_time;
-
-                            println!("Day {:02}", day);
-                            println!("  - {}", $crate::ResultLine::note("Parsing", solution.parse_time));
-                            println!("  - {}", $crate::ResultLine::solution(1, solution.part1_time, solution.part1));
-                            println!("  - {}", $crate::ResultLine::solution(2, solution.part2_time, solution.part2));
-                            println!("  - {}", $crate::ResultLine::note("Total", day_time));
-                            println!();
-                        }
-                    ),+,
-                    x => unimplemented!("Day {} is not yet implemented", x),
-                });
-
-                let total_time = ::humantime::format_duration(total_time);
-                println!("Total time: {}", total_time);
+            $crate::runner::main(&[$($year::YEAR),+]);
         }
     };
 }
@@ -0,0 +1,283 @@
+//! Points in 2D and 3D, with component-wise arithmetic.
+//!
+//! [`MinMax`](crate::MinMax) collects points into their bounding box, with the smallest and
+//! the greatest value of every component.
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{Bounded, MaxDefault, MinDefault};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// The difference of two values, without underflowing for unsigned types.
+fn distance<T: Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+macro_rules! point {
+    ($point:ident, $n:literal, $($c:ident),+) => {
+        impl<T> $point<T> {
+            pub const fn new($($c: T),+) -> Self {
+                Self { $($c),+ }
+            }
+
+            /// Applies `f` to every component.
+            pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> $point<U> {
+                $point { $($c: f(self.$c)),+ }
+            }
+
+            pub fn to_array(self) -> [T; $n] {
+                [$(self.$c),+]
+            }
+        }
+
+        impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> $point<T> {
+            /// The number of straight steps from `self` to `other`.
+            #[must_use]
+            pub fn manhattan(self, other: Self) -> T {
+                let [first, rest @ ..] = self.distances(other).to_array();
+                rest.into_iter().fold(first, |sum, distance| sum + distance)
+            }
+
+            /// The number of straight or diagonal steps from `self` to `other`.
+            #[must_use]
+            pub fn chebyshev(self, other: Self) -> T {
+                let [first, rest @ ..] = self.distances(other).to_array();
+                rest.into_iter().fold(first, Ord::max)
+            }
+
+            fn distances(self, other: Self) -> Self {
+                Self { $($c: distance(self.$c, other.$c)),+ }
+            }
+        }
+
+        impl<T> From<[T; $n]> for $point<T> {
+            fn from([$($c),+]: [T; $n]) -> Self {
+                Self { $($c),+ }
+            }
+        }
+
+        impl<T> From<$point<T>> for [T; $n] {
+            fn from(point: $point<T>) -> Self {
+                point.to_array()
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+
+        /// Scales every component.
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                Self { $($c: self.$c * rhs),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$c += rhs.$c;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$c -= rhs.$c;)+
+            }
+        }
+
+        impl<T: Copy + MulAssign> MulAssign<T> for $point<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                $(self.$c *= rhs;)+
+            }
+        }
+
+        impl<T: MinDefault> MinDefault for $point<T> {
+            fn min_default() -> Self {
+                Self { $($c: T::min_default()),+ }
+            }
+        }
+
+        impl<T: MaxDefault> MaxDefault for $point<T> {
+            fn max_default() -> Self {
+                Self { $($c: T::max_default()),+ }
+            }
+        }
+
+        impl<T: Bounded> Bounded for $point<T> {
+            fn lower(self, other: Self) -> Self {
+                Self { $($c: self.$c.lower(other.$c)),+ }
+            }
+
+            fn upper(self, other: Self) -> Self {
+                Self { $($c: self.$c.upper(other.$c)),+ }
+            }
+        }
+    };
+}
+
+point!(Point2, 2, x, y);
+point!(Point3, 3, x, y, z);
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(Point2 { x, y }: Point2<T>) -> Self {
+        (x, y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(Point3 { x, y, z }: Point3<T>) -> Self {
+        (x, y, z)
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Point3<T> {
+    /// One of the 24 rotations by quarter turns around the axes, `index` is taken modulo 24.
+    ///
+    /// Every index below 4 keeps the direction of the x axis and rolls around it, and every
+    /// following group of 4 turns the x axis into another direction first.
+    /// Rotation 0 is the identity.
+    #[must_use]
+    pub fn rotate(self, index: usize) -> Self {
+        let Self { x, y, z } = self;
+        let (x, y, z) = match index % 24 / 4 {
+            0 => (x, y, z),
+            1 => (-x, -y, z),
+            2 => (y, -x, z),
+            3 => (-y, x, z),
+            4 => (z, y, -x),
+            _ => (-z, y, x),
+        };
+        let (y, z) = match index % 4 {
+            0 => (y, z),
+            1 => (-z, y),
+            2 => (-y, -z),
+            _ => (z, -y),
+        };
+        Self { x, y, z }
+    }
+
+    /// All 24 rotations of the point, in the order of [`Point3::rotate`].
+    pub fn rotations(self) -> impl Iterator<Item = Self> {
+        (0..24).map(move |index| self.rotate(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MinMax;
+
+    #[test]
+    fn test_ops() {
+        let a = Point2::new(3, -2);
+        let b = Point2::from((1, 4));
+        assert_eq!(a + b, Point2::new(4, 2));
+        assert_eq!(a - b, Point2::new(2, -6));
+        assert_eq!(-a, Point2::new(-3, 2));
+        assert_eq!(a * 2, Point2::new(6, -4));
+        assert_eq!((a - b).map(i32::signum), Point2::new(1, -1));
+
+        let mut c = Point3::new(1_u8, 2, 3);
+        c += Point3::new(1, 1, 1);
+        c -= Point3::from([2, 0, 1]);
+        c *= 3;
+        assert_eq!(c.to_array(), [0, 9, 9]);
+        assert_eq!(<(u8, u8, u8)>::from(c), (0, 9, 9));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(3, -2);
+        let b = Point2::new(1, 4);
+        assert_eq!(a.manhattan(b), 8);
+        assert_eq!(a.chebyshev(b), 6);
+
+        let a = Point3::new(5_u32, 0, 7);
+        let b = Point3::new(2, 3, 9);
+        assert_eq!(a.manhattan(b), 8);
+        assert_eq!(b.manhattan(a), 8);
+        assert_eq!(a.chebyshev(b), 3);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let point = Point3::new(1, 2, 3);
+        let mut rotations = point.rotations().collect::<Vec<_>>();
+        assert_eq!(rotations[0], point);
+        assert_eq!(point.rotate(24), point);
+
+        for rotated in &rotations {
+            let mut components = rotated.to_array().map(i32::abs);
+            components.sort_unstable();
+            assert_eq!(components, [1, 2, 3]);
+        }
+        rotations.sort_unstable();
+        rotations.dedup();
+        assert_eq!(rotations.len(), 24);
+
+        // Four quarter turns around the x axis go full circle.
+        let rolled = (0..4).fold(point, |p, _| p.rotate(1));
+        assert_eq!(rolled, point);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [Point2::new(3, -2), Point2::new(-1, 4), Point2::new(0, 0)];
+        let bounds = points.into_iter().collect::<MinMax<_>>();
+        assert_eq!(bounds.min, Point2::new(-1, -2));
+        assert_eq!(bounds.max, Point2::new(3, 4));
+
+        let bounds = [Point3::new(1_u8, 9, 4)].into_iter().collect::<MinMax<_>>();
+        assert_eq!(bounds.min, bounds.max);
+    }
+}
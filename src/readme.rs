@@ -0,0 +1,269 @@
+//! Renders the benchmark tables of the README from the CSV output of `aoc bench`.
+
+use std::fmt::Write as _;
+
+/// Lines of the template that are replaced by the table of one year, e.g. `<!-- bench 2022 -->`.
+const PLACEHOLDER_PREFIX: &str = "<!-- bench ";
+const PLACEHOLDER_SUFFIX: &str = " -->";
+
+pub const TEMPLATE: &str = "README.tpl.md";
+pub const OUTPUT: &str = "README.md";
+
+const HEADER: [&str; 4] = ["Day", "Part", "Mean [µs]", "Throughput"];
+const RIGHT_ALIGNED: [bool; 4] = [false, false, true, true];
+
+/// One row of the CSV written by `aoc bench --format csv`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchResult {
+    pub year: u16,
+    pub day: u8,
+    pub phase: String,
+    pub median_ns: u64,
+    pub deviation_ns: u64,
+    pub mib_per_second: Option<f64>,
+}
+
+/// Parses the CSV written by `aoc bench --format csv`.
+///
+/// # Errors
+/// When the header is missing or a row is malformed.
+pub fn parse_results(csv: &str) -> Result<Vec<BenchResult>, String> {
+    let mut lines = csv.lines().enumerate().filter(|(_, line)| !line.is_empty());
+    let header = lines
+        .next()
+        .map(|(_, header)| header.split(',').collect::<Vec<_>>());
+    let header = header.ok_or("The benchmark results are empty")?;
+
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|h| *h == name)
+            .ok_or_else(|| format!("The benchmark results have no `{name}` column"))
+    };
+    let year = column("year")?;
+    let day = column("day")?;
+    let phase = column("phase")?;
+    let median = column("median_ns")?;
+    let deviation = column("deviation_ns")?;
+    let throughput = column("mib_per_second")?;
+
+    lines
+        .map(|(index, line)| {
+            let fields = line.split(',').collect::<Vec<_>>();
+            let field = |column: usize| {
+                fields.get(column).copied().ok_or_else(|| {
+                    format!(
+                        "Missing column in line {} of the benchmark results",
+                        index + 1
+                    )
+                })
+            };
+            let invalid = |e: &dyn std::fmt::Display| {
+                format!("Invalid line {} of the benchmark results: {e}", index + 1)
+            };
+
+            Ok(BenchResult {
+                year: field(year)?.parse().map_err(|e| invalid(&e))?,
+                day: field(day)?.parse().map_err(|e| invalid(&e))?,
+                phase: field(phase)?.to_owned(),
+                median_ns: field(median)?.parse().map_err(|e| invalid(&e))?,
+                deviation_ns: field(deviation)?.parse().map_err(|e| invalid(&e))?,
+                mib_per_second: match field(throughput)? {
+                    "" => None,
+                    value => Some(value.parse().map_err(|e| invalid(&e))?),
+                },
+            })
+        })
+        .collect()
+}
+
+/// Replaces every placeholder line in `template` with the table of its year.
+/// Years without results render as nothing.
+///
+/// # Errors
+/// When a placeholder does not name a year.
+pub fn render(template: &str, results: &[BenchResult]) -> Result<String, String> {
+    let mut readme = String::with_capacity(template.len());
+
+    for line in template.lines() {
+        let year = line
+            .trim()
+            .strip_prefix(PLACEHOLDER_PREFIX)
+            .and_then(|rest| rest.strip_suffix(PLACEHOLDER_SUFFIX));
+
+        match year {
+            Some(year) => {
+                let year = year
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("Invalid year in placeholder `{}`", line.trim()))?;
+                readme.push_str(&table(year, results));
+            }
+            None => {
+                readme.push_str(line);
+                readme.push('\n');
+            }
+        }
+    }
+
+    Ok(readme)
+}
+
+/// The Markdown table of one year, with padded columns.
+#[must_use]
+pub fn table(year: u16, results: &[BenchResult]) -> String {
+    let mut results = results
+        .iter()
+        .filter(|r| r.year == year)
+        .collect::<Vec<_>>();
+    if results.is_empty() {
+        return String::new();
+    }
+    results.sort_by_key(|r| (r.day, phase_order(&r.phase)));
+
+    let rows = results
+        .into_iter()
+        .map(|r| {
+            [
+                format!("`Day {}`", r.day),
+                format!("`{}`", phase_name(&r.phase)),
+                format!("{} ± {}", micros(r.median_ns), micros(r.deviation_ns)),
+                r.mib_per_second
+                    .map(|tpt| format!("{tpt:.0} MiB/s"))
+                    .unwrap_or_default(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut widths = HEADER.map(|h| h.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    push_row(&mut table, &HEADER, widths);
+    for (width, right) in widths.into_iter().zip(RIGHT_ALIGNED) {
+        let dashes = "-".repeat(width + 1);
+        let _ = if right {
+            write!(table, "|{dashes}:")
+        } else {
+            write!(table, "|:{dashes}")
+        };
+    }
+    table.push_str("|\n");
+    for row in &rows {
+        push_row(&mut table, row, widths);
+    }
+
+    table
+}
+
+fn push_row(table: &mut String, row: &[impl AsRef<str>], widths: [usize; 4]) {
+    for ((cell, width), right) in row.iter().zip(widths).zip(RIGHT_ALIGNED) {
+        let _ = if right {
+            write!(table, "| {:>width$} ", cell.as_ref())
+        } else {
+            write!(table, "| {:<width$} ", cell.as_ref())
+        };
+    }
+    table.push_str("|\n");
+}
+
+/// The names of the former `#[bench]` functions, so that the tables stay the same.
+fn phase_name(phase: &str) -> &str {
+    match phase {
+        "parse" => "parsing",
+        "part1" => "pt1",
+        "part2" => "pt2",
+        other => other,
+    }
+}
+
+fn phase_order(phase: &str) -> usize {
+    ["parse", "part1", "part2"]
+        .iter()
+        .position(|p| *p == phase)
+        .unwrap_or(usize::MAX)
+}
+
+/// Microseconds with at most three decimals, without trailing zeros.
+fn micros(nanos: u64) -> String {
+    let micros = format!("{}.{:03}", nanos / 1000, nanos % 1000);
+    micros
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESULTS: &str = "\
+year,day,phase,samples,median_ns,deviation_ns,outliers,mib_per_second
+2022,10,parse,100,3016,97,1,314.49
+2022,10,part1,100,117,4,0,
+2022,1,part2,100,261,0,0,
+2022,1,parse,100,57710,56,2,182.2
+2021,1,parse,100,27690,284,2,355.0
+";
+
+    #[test]
+    fn test_parse_results() {
+        let results = parse_results(RESULTS).unwrap();
+        assert_eq!(results.len(), 5);
+        assert_eq!(
+            results[0],
+            BenchResult {
+                year: 2022,
+                day: 10,
+                phase: String::from("parse"),
+                median_ns: 3016,
+                deviation_ns: 97,
+                mib_per_second: Some(314.49),
+            }
+        );
+        assert_eq!(results[1].mib_per_second, None);
+    }
+
+    #[test]
+    fn test_parse_results_invalid() {
+        assert!(parse_results("").is_err());
+        assert!(parse_results("year,day\n2022,1\n").is_err());
+        let err = parse_results(&RESULTS.replace("3016", "fast")).unwrap_err();
+        assert!(err.contains("line 2"), "{err}");
+    }
+
+    #[test]
+    fn test_table() {
+        let results = parse_results(RESULTS).unwrap();
+        assert_eq!(
+            table(2022, &results),
+            "\
+| Day      | Part      |     Mean [µs] | Throughput |
+|:---------|:----------|--------------:|-----------:|
+| `Day 1`  | `parsing` | 57.71 ± 0.056 |  182 MiB/s |
+| `Day 1`  | `pt2`     |     0.261 ± 0 |            |
+| `Day 10` | `parsing` | 3.016 ± 0.097 |  314 MiB/s |
+| `Day 10` | `pt1`     | 0.117 ± 0.004 |            |
+"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let results = parse_results(RESULTS).unwrap();
+        let template =
+            "# AoC\n\n## 2021\n\n<!-- bench 2021 -->\n\n## 2020\n\n<!-- bench 2020 -->\n";
+        let readme = render(template, &results).unwrap();
+        assert!(
+            readme.starts_with("# AoC\n\n## 2021\n\n| Day     |"),
+            "{readme}"
+        );
+        assert!(readme.ends_with("## 2020\n\n"), "{readme}");
+
+        assert!(render("<!-- bench next -->", &results).is_err());
+    }
+}
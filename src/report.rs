@@ -0,0 +1,281 @@
+use std::{
+    fmt::{self, Write as _},
+    io::{self, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::{alloc, answers::Verdict, runner::DaySolution, ResultLine};
+
+/// How the runner prints its results.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Colored, human readable lines.
+    #[default]
+    Text,
+    /// One JSON object per line and day.
+    Json,
+    /// A header row followed by one row per day.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            otherwise => Err(format!(
+                "Invalid format `{otherwise}`, expected one of text, json, or csv"
+            )),
+        }
+    }
+}
+
+const CSV_HEADER: &str = "year,day,part1,part2,parse_ns,part1_ns,part2_ns";
+
+/// Writes the results of a run in one [`Format`].
+pub struct Report<W> {
+    out: W,
+    format: Format,
+    show_years: bool,
+}
+
+impl<W: Write> Report<W> {
+    /// `show_years` adds headers and totals per year to the text format.
+    pub fn new(out: W, format: Format, show_years: bool) -> Self {
+        Self {
+            out,
+            format,
+            show_years,
+        }
+    }
+
+    /// # Errors
+    /// When writing fails.
+    pub fn begin(&mut self) -> io::Result<()> {
+        match self.format {
+            Format::Csv => writeln!(self.out, "{CSV_HEADER}"),
+            Format::Text | Format::Json => Ok(()),
+        }
+    }
+
+    /// # Errors
+    /// When writing fails.
+    pub fn begin_year(&mut self, year: u16) -> io::Result<()> {
+        match self.format {
+            Format::Text if self.show_years => writeln!(self.out, "Year {year}\n"),
+            _ => Ok(()),
+        }
+    }
+
+    /// `verdicts` are only shown in the text format.
+    ///
+    /// # Errors
+    /// When writing fails.
+    pub fn day(
+        &mut self,
+        year: u16,
+        day: u8,
+        solution: &DaySolution,
+        verdicts: &[Option<Verdict>; 2],
+    ) -> io::Result<()> {
+        match self.format {
+            Format::Text => self.day_text(&format!("Day {day:02}"), solution, verdicts),
+            Format::Json => {
+                let line = json_record(year, day, solution);
+                writeln!(self.out, "{line}")
+            }
+            Format::Csv => {
+                let line = csv_record(year, day, solution);
+                writeln!(self.out, "{line}")
+            }
+        }
+    }
+
+    /// The solution of an example instead of the puzzle input, only in the text format.
+    ///
+    /// # Errors
+    /// When writing fails.
+    pub fn example(
+        &mut self,
+        day: u8,
+        name: &str,
+        solution: &DaySolution,
+        verdicts: &[Option<Verdict>; 2],
+    ) -> io::Result<()> {
+        self.day_text(&format!("Day {day:02} {name}"), solution, verdicts)
+    }
+
+    fn day_text(
+        &mut self,
+        header: &str,
+        solution: &DaySolution,
+        verdicts: &[Option<Verdict>; 2],
+    ) -> io::Result<()> {
+        let day_time = solution.total_time();
+
+        writeln!(self.out, "{header}")?;
+        let mut parsing = ResultLine::note("Parsing", solution.parse_time);
+        if alloc::ENABLED {
+            parsing = parsing.with_allocs(solution.parse_allocs);
+        }
+        writeln!(self.out, "  - {parsing}")?;
+
+        let parts = [
+            (&solution.part1, solution.part1_time, solution.part1_allocs),
+            (&solution.part2, solution.part2_time, solution.part2_allocs),
+        ];
+        for (part, ((answer, time, allocs), verdict)) in (1..).zip(parts.into_iter().zip(verdicts))
+        {
+            if let Some(answer) = answer {
+                let mut line = ResultLine::solution(part, time, answer.clone());
+                if alloc::ENABLED {
+                    line = line.with_allocs(allocs);
+                }
+                if let Some(verdict) = verdict {
+                    line = line.with_verdict(verdict.clone());
+                }
+                writeln!(self.out, "  - {line}")?;
+            }
+        }
+        writeln!(self.out, "  - {}", ResultLine::note("Total", day_time))?;
+        writeln!(self.out)
+    }
+
+    /// # Errors
+    /// When writing fails.
+    pub fn end_year(&mut self, year: u16, time: Duration) -> io::Result<()> {
+        match self.format {
+            Format::Text if self.show_years => {
+                let time = humantime::format_duration(time);
+                writeln!(self.out, "Total time for {year}: {time}\n")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// `time` is the sum of all days, `wall_time` is the real time of the run,
+    /// which is shorter if days were solved in parallel.
+    ///
+    /// # Errors
+    /// When writing fails.
+    pub fn end(&mut self, time: Duration, wall_time: Duration) -> io::Result<()> {
+        match self.format {
+            Format::Text => {
+                let time = humantime::format_duration(time);
+                let wall_time = humantime::format_duration(wall_time);
+                writeln!(self.out, "Total time: {time}")?;
+                writeln!(self.out, "Wall time: {wall_time}")
+            }
+            Format::Json | Format::Csv => Ok(()),
+        }
+    }
+}
+
+fn json_record(year: u16, day: u8, solution: &DaySolution) -> String {
+    format!(
+        r#"{{"year":{year},"day":{day},"part1":{},"part2":{},"parse_ns":{},"part1_ns":{},"part2_ns":{}}}"#,
+        JsonString(solution.part1.as_deref()),
+        JsonString(solution.part2.as_deref()),
+        solution.parse_time.as_nanos(),
+        solution.part1_time.as_nanos(),
+        solution.part2_time.as_nanos(),
+    )
+}
+
+fn csv_record(year: u16, day: u8, solution: &DaySolution) -> String {
+    format!(
+        "{year},{day},{},{},{},{},{}",
+        CsvField(solution.part1.as_deref().unwrap_or_default()),
+        CsvField(solution.part2.as_deref().unwrap_or_default()),
+        solution.parse_time.as_nanos(),
+        solution.part1_time.as_nanos(),
+        solution.part2_time.as_nanos(),
+    )
+}
+
+/// A JSON string literal, or `null`.
+struct JsonString<'a>(Option<&'a str>);
+
+impl fmt::Display for JsonString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(s) = self.0 else {
+            return f.write_str("null");
+        };
+
+        f.write_char('"')?;
+        for c in s.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                c if c.is_control() => write!(f, "\\u{:04x}", u32::from(c))?,
+                c => f.write_char(c)?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+/// A CSV field, quoted as per RFC 4180 if necessary.
+struct CsvField<'a>(&'a str);
+
+impl fmt::Display for CsvField<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.contains([',', '"', '\n', '\r']) {
+            write!(f, "\"{}\"", self.0.replace('"', "\"\""))
+        } else {
+            f.write_str(self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solution(part1: &str, part2: Option<&str>) -> DaySolution {
+        DaySolution {
+            part1: Some(part1.to_owned()),
+            part2: part2.map(str::to_owned),
+            parse_time: Duration::from_nanos(1),
+            part1_time: Duration::from_nanos(20),
+            part2_time: Duration::from_micros(3),
+            ..DaySolution::default()
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        let record = json_record(2022, 10, &solution("13140", Some("#.\"\\\n.#")));
+        assert_eq!(
+            record,
+            r##"{"year":2022,"day":10,"part1":"13140","part2":"#.\"\\\n.#","parse_ns":1,"part1_ns":20,"part2_ns":3000}"##
+        );
+    }
+
+    #[test]
+    fn test_json_unselected_part() {
+        let record = json_record(2020, 1, &solution("42", None));
+        assert!(record.contains(r#""part2":null"#), "{record}");
+    }
+
+    #[test]
+    fn test_total_time_unselected_part() {
+        let mut part2_only = solution("42", Some("7"));
+        part2_only.part1 = None;
+        assert_eq!(part2_only.total_time(), Duration::from_nanos(3001));
+        assert_eq!(solution("42", None).total_time(), Duration::from_nanos(21));
+    }
+
+    #[test]
+    fn test_csv() {
+        let record = csv_record(2022, 10, &solution("1,2", Some("#.\"\n.#")));
+        assert_eq!(record, "2022,10,\"1,2\",\"#.\"\"\n.#\",1,20,3000");
+    }
+}
@@ -0,0 +1,834 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs::File,
+    io::{self, BufWriter},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread::{self, Scope},
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::{
+    answers::{Answers, Verdict},
+    bench::{self, BenchReport},
+    cli::{Args, Command, Days, Selector},
+    client::{Client, Outcome},
+    examples,
+    guesses::{Guess, Guesses},
+    input::{self, Source},
+    junit::JUnit,
+    readme,
+    report::{Format, Report},
+    scaffold, ParseError, Parts, PuzzleSolution, Solution,
+};
+
+/// All registered days of one year, as generated by `aoc_year!`.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    #[must_use]
+    pub fn day(&self, day: u8) -> Option<&Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+
+    #[must_use]
+    pub fn latest_day(&self) -> Option<u8> {
+        self.days.iter().map(|d| d.day).max()
+    }
+
+    /// Resolves the command line selection against the days of this year.
+    /// `latest` tells if this is the latest of the selected years.
+    #[must_use]
+    pub fn select(&self, selectors: &[Selector], latest: bool) -> Vec<(&Day, Parts)> {
+        let matches = |selector: &Selector, day: u8| match selector.days {
+            Days::Latest => latest && self.latest_day() == Some(day),
+            days => days.contains(day),
+        };
+        let has_includes = selectors.iter().any(|s| !s.exclude);
+
+        self.days
+            .iter()
+            .filter_map(|day| {
+                let mut parts = if has_includes {
+                    Parts::NONE
+                } else {
+                    Parts::BOTH
+                };
+                for selector in selectors.iter().filter(|s| matches(s, day.day)) {
+                    if !selector.exclude {
+                        parts = parts.union(selector.parts);
+                    }
+                }
+                for selector in selectors.iter().filter(|s| matches(s, day.day)) {
+                    if selector.exclude {
+                        parts = parts.without(selector.parts);
+                    }
+                }
+                (!parts.is_empty()).then_some((day, parts))
+            })
+            .collect()
+    }
+}
+
+/// The answers of a [`Day`], formatted for display. Unselected parts are `None`.
+pub type DaySolution = PuzzleSolution<Option<String>>;
+
+/// A type-erased [`Solution`], so that days with different outputs can be run together.
+#[derive(Copy, Clone)]
+pub struct Day {
+    pub day: u8,
+    puzzle_input: fn() -> io::Result<&'static str>,
+    solve: fn(&str, Parts) -> Result<DaySolution, ParseError>,
+    sample: fn(&str) -> PuzzleSolution<()>,
+}
+
+impl Day {
+    #[must_use]
+    pub const fn new<S>(day: u8) -> Self
+    where
+        S: Solution,
+        S::Output: Display,
+    {
+        Self {
+            day,
+            puzzle_input: S::try_puzzle_input,
+            solve: solve::<S>,
+            sample: bench::sample::<S>,
+        }
+    }
+
+    /// # Errors
+    /// When the input cannot be read.
+    pub fn puzzle_input(&self) -> io::Result<&'static str> {
+        (self.puzzle_input)()
+    }
+
+    /// # Errors
+    /// When the input is invalid.
+    pub fn solve(&self, input: &str, parts: Parts) -> Result<DaySolution, ParseError> {
+        (self.solve)(input, parts).map_err(|e| e.with_day(self.day))
+    }
+
+    /// Runs all parts once without checking the input, for [`bench::measure`].
+    #[must_use]
+    pub fn sample(&self, input: &str) -> PuzzleSolution<()> {
+        (self.sample)(input)
+    }
+}
+
+fn solve<S>(input: &str, parts: Parts) -> Result<DaySolution, ParseError>
+where
+    S: Solution,
+    S::Output: Display,
+{
+    S::try_solve_on(input, parts)
+        .map(|solution| solution.map(|answer| answer.map(|answer| answer.to_string())))
+}
+
+/// Entry point of the binaries, see `aoc_main!`.
+pub fn main(years: &[Year]) {
+    let args = Args::from_env();
+    if let Some(dir) = &args.input_dir {
+        input::set_dir(dir);
+    }
+    match args.command {
+        Command::Readme => return readme(&args),
+        Command::Fetch => return fetch(&args),
+        Command::New => return new_day(&args),
+        Command::Run | Command::Bench | Command::Submit => {}
+    }
+
+    let selected = select(years, &args).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        std::process::exit(2);
+    });
+
+    if args.input.is_some() && selected.iter().map(|(_, days)| days.len()).sum::<usize>() != 1 {
+        eprintln!("--input can only be used with a single day");
+        std::process::exit(2);
+    }
+    if args.input.is_some() && args.command == Command::Submit {
+        eprintln!("submit cannot be used with --input, the answer must be for the puzzle input");
+        std::process::exit(2);
+    }
+    if args.example {
+        let conflict = if args.command != Command::Run {
+            Some("--example can only be used with run")
+        } else if args.input.is_some() || args.check {
+            Some("--example cannot be used with --input or --check")
+        } else if args.format != Format::Text {
+            Some("--example only supports the text format")
+        } else {
+            None
+        };
+        if let Some(conflict) = conflict {
+            eprintln!("{conflict}");
+            std::process::exit(2);
+        }
+    }
+    if args.input.is_some() && args.check {
+        eprintln!(
+            "--check cannot be used with --input, the known answers are for the puzzle inputs"
+        );
+        std::process::exit(2);
+    }
+
+    match args.command {
+        Command::Run if args.example => run_examples(selected),
+        Command::Run => run(selected, &args),
+        Command::Bench => bench(selected, &args),
+        Command::Submit => submit(&selected, &args),
+        Command::Readme | Command::Fetch | Command::New => {
+            unreachable!("handled before the selection")
+        }
+    }
+}
+
+fn try_read_input(day: &Day, args: &Args) -> Result<&'static str, String> {
+    let input = match &args.input {
+        Some(source) => source.read(),
+        None => day.puzzle_input(),
+    };
+    input.map_err(|e| format!("Day {:02}: {}", day.day, e))
+}
+
+fn solve_day(day: &Day, parts: Parts, args: &Args) -> Result<DaySolution, String> {
+    let input = try_read_input(day, args)?;
+    day.solve(input, parts).map_err(|e| e.to_string())
+}
+
+/// Solves the `tasks` on `args.jobs` threads, and yields the solutions in the order of `tasks`.
+/// A day that fails, e.g. because its input is missing, yields its error in that same order.
+fn solve_in_order<'scope>(
+    scope: &'scope Scope<'scope, '_>,
+    tasks: &'scope [(&Day, Parts)],
+    args: &'scope Args,
+) -> Box<dyn Iterator<Item = Result<DaySolution, String>> + 'scope> {
+    if args.jobs <= 1 {
+        let solutions = tasks
+            .iter()
+            .map(|&(day, parts)| solve_day(day, parts, args));
+        return Box::new(solutions);
+    }
+
+    let next_task = Arc::new(AtomicUsize::new(0));
+    let (sender, receiver) = mpsc::channel();
+    for _ in 0..args.jobs.min(tasks.len()) {
+        let next_task = Arc::clone(&next_task);
+        let sender = sender.clone();
+        scope.spawn(move || loop {
+            let index = next_task.fetch_add(1, Ordering::Relaxed);
+            let Some(&(day, parts)) = tasks.get(index) else { break };
+            if sender.send((index, solve_day(day, parts, args))).is_err() {
+                break;
+            }
+        });
+    }
+    drop(sender);
+
+    let mut pending = BTreeMap::new();
+    let mut next = 0;
+    let solutions = std::iter::from_fn(move || loop {
+        if let Some(solution) = pending.remove(&next) {
+            next += 1;
+            return Some(solution);
+        }
+        let (index, solution) = receiver.recv().ok()?;
+        pending.insert(index, solution);
+    });
+    Box::new(solutions)
+}
+
+fn run(selected: Selection<'_>, args: &Args) {
+    let tasks = selected
+        .iter()
+        .flat_map(|(_, days)| days.iter().copied())
+        .collect::<Vec<_>>();
+    let start = Instant::now();
+    thread::scope(|scope| {
+        let solutions = solve_in_order(scope, &tasks, args);
+        report(selected, solutions, start, args);
+    });
+}
+
+fn run_examples(selected: Selection<'_>) {
+    let show_years = selected.len() > 1;
+    let mut report = Report::new(io::stdout().lock(), Format::Text, show_years);
+    let mut wrong = 0;
+    let mut missing = Vec::new();
+
+    for (year, days) in selected {
+        report.begin_year(year).unwrap();
+        for (day, parts) in days {
+            let examples = examples::load_all(&examples::dir(year), day.day).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            if examples.is_empty() {
+                missing.push(format!("{year} day {}", day.day));
+            }
+
+            for example in examples {
+                let solution = day.solve(&example.input, parts).unwrap_or_else(|e| {
+                    eprintln!("{} of {year} day {}: {e}", example.name, day.day);
+                    std::process::exit(1);
+                });
+
+                let mut verdicts = [None, None];
+                for (part, answer) in (1..).zip([&solution.part1, &solution.part2]) {
+                    let Some(answer) = answer else { continue };
+                    let verdict = example.check(part, answer);
+                    wrong += usize::from(verdict.is_wrong());
+                    verdicts[usize::from(part - 1)] = Some(verdict);
+                }
+                report
+                    .example(day.day, &example.name, &solution, &verdicts)
+                    .unwrap();
+            }
+        }
+    }
+
+    if !missing.is_empty() {
+        eprintln!("No examples for {}", missing.join(", "));
+    }
+    if wrong > 0 {
+        eprintln!("{wrong} wrong answer(s)");
+        std::process::exit(1);
+    }
+}
+
+fn report(
+    selected: Selection<'_>,
+    mut solutions: impl Iterator<Item = Result<DaySolution, String>>,
+    start: Instant,
+    args: &Args,
+) {
+    let show_years = selected.len() > 1;
+    let mut report = Report::new(io::stdout().lock(), args.format, show_years);
+    let mut junit = JUnit::default();
+    let mut wrong = 0;
+    let mut failed = 0;
+    let mut total_time = Duration::ZERO;
+
+    report.begin().unwrap();
+    for (year, days) in selected {
+        report.begin_year(year).unwrap();
+
+        let answers = args.check.then(|| {
+            Answers::load(args.answers_dir.as_deref(), year).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(2);
+            })
+        });
+
+        let mut year_time = Duration::ZERO;
+        for (day, _) in days {
+            let solution = solutions.next().expect("one solution per selected day");
+            let solution = match solution {
+                Ok(solution) => solution,
+                Err(e) => {
+                    eprintln!("{e}");
+                    failed += 1;
+                    continue;
+                }
+            };
+            year_time += solution.total_time();
+
+            let mut verdicts = [None, None];
+            if let Some(answers) = &answers {
+                let parts = [
+                    (&solution.part1, solution.part1_time),
+                    (&solution.part2, solution.part2_time),
+                ];
+                for (part, (answer, time)) in (1..).zip(parts) {
+                    let Some(answer) = answer else { continue };
+                    let verdict = answers.check(day.day, part, answer);
+                    wrong += usize::from(verdict.is_wrong());
+                    junit.add(year, day.day, part, time, answer, verdict.clone());
+                    verdicts[usize::from(part - 1)] = Some(verdict);
+                }
+            }
+
+            report.day(year, day.day, &solution, &verdicts).unwrap();
+        }
+
+        report.end_year(year, year_time).unwrap();
+        total_time += year_time;
+    }
+    report.end(total_time, start.elapsed()).unwrap();
+
+    if let Some(path) = &args.junit {
+        let written = File::create(path).and_then(|file| junit.write(BufWriter::new(file)));
+        if let Err(e) = written {
+            eprintln!("Could not write {}: {e}", path.display());
+            std::process::exit(1);
+        }
+    }
+    if failed > 0 {
+        eprintln!("{failed} day(s) failed");
+    }
+    if wrong > 0 {
+        eprintln!("{wrong} wrong answer(s)");
+    }
+    if failed > 0 || wrong > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn readme(args: &Args) {
+    let results = args.results.clone().unwrap_or(Source::Stdin);
+    let readme = results
+        .read()
+        .map_err(|e| e.to_string())
+        .and_then(readme::parse_results)
+        .and_then(|results| {
+            let template = std::fs::read_to_string(readme::TEMPLATE)
+                .map_err(|e| format!("Could not read {}: {e}", readme::TEMPLATE))?;
+            readme::render(&template, &results)
+        })
+        .and_then(|readme| {
+            std::fs::write(readme::OUTPUT, readme)
+                .map_err(|e| format!("Could not write {}: {e}", readme::OUTPUT))
+        });
+
+    if let Err(e) = readme {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
+}
+
+fn fetch(args: &Args) {
+    let days = explicit_days(args).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        std::process::exit(2);
+    });
+    let missing = days
+        .into_iter()
+        .map(|(year, day)| (year, day, input::repo_path(year, day)))
+        .filter(|(year, day, path)| {
+            // `aoc new` leaves an empty input behind, which still has to be downloaded.
+            let exists = std::fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0);
+            if exists {
+                println!("Input of {year} day {day} is already at {}", path.display());
+            }
+            !exists
+        })
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        return;
+    }
+
+    let client = Client::new(args.base_url.clone()).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        std::process::exit(1);
+    });
+    for (year, day, path) in missing {
+        let written = client
+            .input(year, day)
+            .map_err(|e| e.to_string())
+            .and_then(|input| {
+                let write = |path: &std::path::Path| {
+                    std::fs::create_dir_all(path.parent().unwrap_or(path))?;
+                    std::fs::write(path, input)
+                };
+                write(&path).map_err(|e| format!("Could not write {}: {e}", path.display()))
+            });
+        match written {
+            Ok(()) => println!(
+                "Downloaded the input of {year} day {day} to {}",
+                path.display()
+            ),
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+fn new_day(args: &Args) {
+    let (&[year], &[Selector { days: Days::Day(day), .. }]) =
+        (args.years.as_slice(), args.selectors.as_slice())
+    else {
+        unreachable!("one year and day are selected")
+    };
+
+    match scaffold::new_day(year, day) {
+        Ok(changed) => {
+            for path in changed {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn submit(selected: &Selection<'_>, args: &Args) {
+    let exit = |e: String| -> ! {
+        eprintln!("{e}");
+        std::process::exit(1);
+    };
+    let [(year, days)] = selected.as_slice() else { unreachable!("one year is selected") };
+    let [(day, parts)] = days.as_slice() else { unreachable!("one day is selected") };
+    let (year, part) = (*year, if parts.part1 { 1 } else { 2 });
+
+    let solution = solve_day(day, *parts, args).unwrap_or_else(|e| exit(e));
+    let answer = if part == 1 {
+        solution.part1
+    } else {
+        solution.part2
+    };
+    let answer = answer.expect("the selected part is solved");
+    let answer = answer.trim();
+    println!("{year} day {} part {part}: {answer}", day.day);
+
+    let answers = Answers::load(args.answers_dir.as_deref(), year).unwrap_or_else(|e| exit(e));
+    match answers.check(day.day, part, answer) {
+        Verdict::Correct => return println!("Already solved, not submitting it again"),
+        Verdict::Wrong { expected } => {
+            exit(format!(
+                "Already solved with {expected}, this answer is wrong"
+            ));
+        }
+        Verdict::Unknown => {}
+    }
+
+    let path = Guesses::default_path(year);
+    let mut guesses = Guesses::load(&path).unwrap_or_else(|e| exit(e));
+    if let Some(wait) = guesses.wait_left(day.day, SystemTime::now()) {
+        exit(format!("{}, not submitting it", Outcome::Wait(wait)));
+    }
+    if let Some(reason) = guesses.check(day.day, part, answer) {
+        exit(format!("{reason}, not submitting it"));
+    }
+
+    let outcome = Client::new(args.base_url.clone())
+        .and_then(|client| client.submit(year, day.day, part, answer))
+        .unwrap_or_else(|e| exit(e.to_string()));
+    if let Outcome::Wait(wait) = outcome {
+        guesses.wait(day.day, SystemTime::now() + wait);
+        guesses.save(&path).unwrap_or_else(|e| exit(e));
+    } else if let Some(guess) = Guess::from_outcome(outcome) {
+        guesses.record(day.day, part, answer, guess);
+        guesses.save(&path).unwrap_or_else(|e| exit(e));
+    }
+    if outcome != Outcome::Correct {
+        exit(outcome.to_string());
+    }
+    println!("{outcome}");
+}
+
+/// The days of the command line that do not need to be implemented, e.g. to download their input.
+/// Needs at least one year and only accepts single days or ranges.
+fn explicit_days(args: &Args) -> Result<Vec<(u16, u8)>, String> {
+    if args.years.is_empty() {
+        return Err(String::from("Select at least one year"));
+    }
+
+    let mut days = Vec::new();
+    for selector in args.selectors.iter().filter(|s| !s.exclude) {
+        match selector.days {
+            Days::Day(day) => days.push(day),
+            Days::Range(from, to) => days.extend(from..=to),
+            Days::All | Days::Latest => {
+                return Err(String::from("Select the days by number, e.g. `5` or `1-3`"))
+            }
+        }
+    }
+    days.retain(|day| {
+        !args
+            .selectors
+            .iter()
+            .any(|s| s.exclude && s.days.contains(*day))
+    });
+    days.sort_unstable();
+    days.dedup();
+    if days.is_empty() {
+        return Err(String::from("No days selected"));
+    }
+
+    Ok(args
+        .years
+        .iter()
+        .flat_map(|&year| days.iter().map(move |&day| (year, day)))
+        .collect())
+}
+
+fn bench(selected: Selection<'_>, args: &Args) {
+    let mut report = BenchReport::new(io::stdout().lock(), args.format);
+
+    let mut failed = 0;
+
+    report.begin().unwrap();
+    for (year, days) in selected {
+        for (day, parts) in days {
+            // The benchmark uses the panicking parsers, so reject invalid input up front.
+            let input = try_read_input(day, args).and_then(|input| {
+                day.solve(input, Parts::NONE)
+                    .map(|_| input)
+                    .map_err(|e| e.to_string())
+            });
+            let input = match input {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("{e}");
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            let measurement = bench::measure(day, input);
+            report.day(year, day.day, parts, &measurement).unwrap();
+        }
+    }
+
+    if failed > 0 {
+        eprintln!("{failed} day(s) failed");
+        std::process::exit(1);
+    }
+}
+
+type Selection<'a> = Vec<(u16, Vec<(&'a Day, Parts)>)>;
+
+fn select<'a>(years: &'a [Year], args: &Args) -> Result<Selection<'a>, String> {
+    if let Some(missing) = args
+        .years
+        .iter()
+        .find(|y| !years.iter().any(|year| year.year == **y))
+    {
+        let available = years
+            .iter()
+            .map(|year| year.year.to_string())
+            .collect::<Vec<_>>();
+        return Err(format!(
+            "Year {missing} is not available, try one of {}",
+            available.join(", ")
+        ));
+    }
+
+    let years = years
+        .iter()
+        .filter(|year| args.years.is_empty() || args.years.contains(&year.year))
+        .collect::<Vec<_>>();
+    let latest_year = years.iter().map(|year| year.year).max();
+
+    for selector in args.selectors.iter().filter(|s| !s.exclude) {
+        if let Days::Day(day) = selector.days {
+            if years.iter().all(|year| year.day(day).is_none()) {
+                return Err(format!("Day {day} is not implemented yet"));
+            }
+        }
+    }
+
+    let selected = years
+        .into_iter()
+        .map(|year| {
+            let days = year.select(&args.selectors, latest_year == Some(year.year));
+            (year.year, days)
+        })
+        .filter(|(_, days)| !days.is_empty())
+        .collect::<Vec<_>>();
+
+    if selected.is_empty() {
+        return Err(String::from("No days selected"));
+    }
+
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A day that answers `N` to both parts, after sleeping for `N` milliseconds so that
+    /// the days of a parallel run finish out of order.
+    struct Answer<const N: u64>;
+
+    impl<const N: u64> Solution for Answer<N> {
+        type Input = ();
+        type Output = u64;
+
+        fn try_puzzle_input() -> io::Result<&'static str> {
+            Ok("")
+        }
+
+        fn run_parts(
+            _input: (),
+            parse_time: Duration,
+            parts: Parts,
+        ) -> PuzzleSolution<Option<u64>> {
+            thread::sleep(Duration::from_millis(N));
+            PuzzleSolution {
+                part1: parts.part1.then_some(N),
+                part2: parts.part2.then_some(N),
+                parse_time,
+                ..PuzzleSolution::default()
+            }
+        }
+    }
+
+    /// A day whose input is missing.
+    struct Missing;
+
+    impl Solution for Missing {
+        type Input = ();
+        type Output = u64;
+
+        fn try_puzzle_input() -> io::Result<&'static str> {
+            Err(io::Error::new(io::ErrorKind::NotFound, "missing"))
+        }
+
+        fn run_parts(
+            _input: (),
+            _parse_time: Duration,
+            _parts: Parts,
+        ) -> PuzzleSolution<Option<u64>> {
+            unreachable!("the input is missing")
+        }
+    }
+
+    const DAYS: &[Day] = &[
+        Day::new::<Answer<1>>(1),
+        Day::new::<Answer<2>>(2),
+        Day::new::<Answer<3>>(3),
+        Day::new::<Answer<5>>(5),
+    ];
+
+    fn args(args: &[&str]) -> Args {
+        Args::parse(args.iter().map(|&arg| arg.to_owned()))
+            .unwrap()
+            .unwrap()
+    }
+
+    fn selected_days(year: &Year, selection: &[&str], latest: bool) -> Vec<(u8, Parts)> {
+        year.select(&args(selection).selectors, latest)
+            .into_iter()
+            .map(|(day, parts)| (day.day, parts))
+            .collect()
+    }
+
+    #[test]
+    fn test_year_select() {
+        let year = Year {
+            year: 2022,
+            days: DAYS,
+        };
+        let part2 = Parts::only(2).unwrap();
+
+        assert_eq!(
+            selected_days(&year, &[], true),
+            [
+                (1, Parts::BOTH),
+                (2, Parts::BOTH),
+                (3, Parts::BOTH),
+                (5, Parts::BOTH)
+            ]
+        );
+        assert_eq!(
+            selected_days(&year, &["2-4", "^3"], true),
+            [(2, Parts::BOTH)]
+        );
+        assert_eq!(
+            selected_days(&year, &["^1-3:1"], true),
+            [(1, part2), (2, part2), (3, part2), (5, Parts::BOTH)]
+        );
+        assert_eq!(selected_days(&year, &["latest"], true), [(5, Parts::BOTH)]);
+        assert_eq!(selected_days(&year, &["latest"], false), []);
+
+        // Days that do not exist or are out of the implemented range select nothing.
+        assert_eq!(selected_days(&year, &["4"], true), []);
+        assert_eq!(selected_days(&year, &["20-25"], true), []);
+    }
+
+    #[test]
+    fn test_select() {
+        let years = [
+            Year {
+                year: 2021,
+                days: &DAYS[..2],
+            },
+            Year {
+                year: 2022,
+                days: DAYS,
+            },
+        ];
+        let select = |selection: &[&str]| {
+            select(&years, &args(selection)).map(|selected| {
+                selected
+                    .into_iter()
+                    .map(|(year, days)| (year, days.into_iter().map(|(day, _)| day.day).collect()))
+                    .collect::<Vec<(u16, Vec<u8>)>>()
+            })
+        };
+
+        assert_eq!(
+            select(&["2", "latest"]),
+            Ok(vec![(2021, vec![2]), (2022, vec![2, 5])])
+        );
+        assert_eq!(
+            select(&["4"]),
+            Err(String::from("Day 4 is not implemented yet"))
+        );
+        assert_eq!(
+            select(&["2021", "3"]),
+            Err(String::from("Day 3 is not implemented yet"))
+        );
+        assert_eq!(
+            select(&["2021", "20-25"]),
+            Err(String::from("No days selected"))
+        );
+        assert_eq!(
+            select(&["2019", "1"]),
+            Err(String::from(
+                "Year 2019 is not available, try one of 2021, 2022"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_solve_in_order() {
+        let days = [
+            Day::new::<Answer<40>>(1),
+            Day::new::<Answer<30>>(2),
+            Day::new::<Missing>(3),
+            Day::new::<Answer<0>>(4),
+            Day::new::<Answer<20>>(5),
+            Day::new::<Answer<10>>(6),
+        ];
+        let tasks = days
+            .iter()
+            .map(|day| (day, Parts::only(day.day % 2 + 1).unwrap()))
+            .collect::<Vec<_>>();
+
+        for jobs in [1, 2, 4, 8] {
+            let args = Args { jobs, ..args(&[]) };
+            let solutions = thread::scope(|scope| {
+                solve_in_order(scope, &tasks, &args)
+                    .map(|solution| solution.map(|solution| [solution.part1, solution.part2]))
+                    .collect::<Vec<_>>()
+            });
+            let answer = |answer: &str| Some(answer.to_owned());
+            assert_eq!(
+                solutions,
+                [
+                    Ok([None, answer("40")]),
+                    Ok([answer("30"), None]),
+                    Err(String::from("Day 03: missing")),
+                    Ok([answer("0"), None]),
+                    Ok([None, answer("20")]),
+                    Ok([answer("10"), None]),
+                ],
+                "{jobs} jobs"
+            );
+        }
+    }
+}
@@ -0,0 +1,287 @@
+//! `aoc new`, which creates the module, input file, and examples of a day and registers it,
+//! and creates the binary of a year that does not exist yet.
+
+use std::path::{Path, PathBuf};
+
+use crate::{examples, input};
+
+/// The module of a new day, `{{day}}` is replaced with the number of the day.
+const DAY_TEMPLATE: &str = r#"type Input = u64;
+type Output = usize;
+
+register!(
+    "input/day{{day}}.txt";
+    (input: input!(parse Input)) -> Output {
+        part1(&input);
+        part2(&input);
+    }
+);
+
+fn part1(items: &[Input]) -> Output {
+    items.len()
+}
+
+fn part2(items: &[Input]) -> Output {
+    items.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    examples!("examples/day{{day}}"; ex1);
+}
+"#;
+
+/// The answers of the first example of a new day.
+const EXAMPLE_ANSWERS_TEMPLATE: &str = "[ex1]\npart1 = 0\npart2 = 0\n";
+
+/// The binary of a new year, `{{year}}` is replaced with the year.
+const YEAR_MAIN_TEMPLATE: &str = r#"#![cfg_attr(all(test, feature = "nightly"), feature(test))]
+#![warn(
+    clippy::all,
+    clippy::pedantic,
+    clippy::nursery,
+    clippy::cargo,
+    rust_2018_idioms
+)]
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss,
+    clippy::fallible_impl_from,
+    clippy::iter_with_drain,
+    clippy::missing_const_for_fn,
+    clippy::multiple_crate_versions,
+    clippy::redundant_pub_crate,
+    clippy::similar_names,
+    elided_lifetimes_in_paths
+)]
+
+#[macro_use]
+extern crate aoc;
+#[cfg(all(test, feature = "nightly"))]
+extern crate test;
+
+#[path = "mod.rs"]
+mod aoc{{year}};
+
+aoc_main!(aoc{{year}});
+"#;
+
+/// Creates everything that is missing for `day` of `year`, and returns the created or changed
+/// files.
+///
+/// # Errors
+/// When the day already exists, or a file cannot be read, changed, or written.
+pub fn new_day(year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let year_dir = root.join("src").join("bin").join(format!("aoc{year}"));
+    let day_file = year_dir.join(format!("day{day}.rs"));
+    let year_mod = year_dir.join("mod.rs");
+    if day_file.exists() {
+        return Err(format!(
+            "Day {day} of {year} already exists at {}",
+            day_file.display()
+        ));
+    }
+
+    let mut changed = Vec::new();
+    if !year_mod.exists() {
+        changed.extend(new_year(root, year)?);
+    }
+
+    write(
+        &day_file,
+        &DAY_TEMPLATE.replace("{{day}}", &day.to_string()),
+    )?;
+    changed.push(day_file);
+
+    // An empty input keeps `embed-inputs` building until `aoc fetch` downloads the real one.
+    let input = input::repo_path(year, day);
+    let example = year_dir
+        .join(examples::DIR)
+        .join(format!("day{day}.ex1.txt"));
+    let example_answers = year_dir.join(examples::DIR).join(format!("day{day}.toml"));
+    for (path, content) in [
+        (input, ""),
+        (example, ""),
+        (example_answers, EXAMPLE_ANSWERS_TEMPLATE),
+    ] {
+        if !path.exists() {
+            write(&path, content)?;
+            changed.push(path);
+        }
+    }
+
+    update(&year_mod, |year_mod| register_day(year_mod, day))?;
+    if !changed.contains(&year_mod) {
+        changed.push(year_mod);
+    }
+
+    Ok(changed)
+}
+
+/// Creates the module and binary of a year, and adds the year to the `aoc` binary.
+fn new_year(root: &Path, year: u16) -> Result<Vec<PathBuf>, String> {
+    let year_dir = root.join("src").join("bin").join(format!("aoc{year}"));
+    let year_mod = year_dir.join("mod.rs");
+    let year_main = year_dir.join("main.rs");
+    let aoc_main = root.join("src").join("bin").join("aoc").join("main.rs");
+    let manifest = root.join("Cargo.toml");
+
+    write(&year_mod, &year_module(year))?;
+    write(
+        &year_main,
+        &YEAR_MAIN_TEMPLATE.replace("{{year}}", &year.to_string()),
+    )?;
+    update(&aoc_main, |main| register_year(main, year))?;
+    update(&manifest, |manifest| Ok(add_bin(manifest, year)))?;
+
+    Ok(vec![year_mod, year_main, aoc_main, manifest])
+}
+
+/// The `aoc_year!` list of a new year, with every day commented out.
+fn year_module(year: u16) -> String {
+    let mut module = format!("aoc_year!(\n    {year};\n");
+    for day in 1..=25 {
+        module.push_str(&format!("    // {day} => day{day},\n"));
+    }
+    module.push_str(");\n");
+    module
+}
+
+/// Enables `day` in the `aoc_year!` list of a year module, by uncommenting or adding its line.
+fn register_day(year_mod: &str, day: u8) -> Result<String, String> {
+    let entry = format!("{day} => day{day},");
+    let mut lines = year_mod.lines().map(String::from).collect::<Vec<_>>();
+
+    if lines.iter().any(|line| line.trim() == entry) {
+        return Ok(year_mod.to_owned());
+    }
+    if let Some(line) = lines
+        .iter_mut()
+        .find(|line| line.trim().strip_prefix("//").map(str::trim) == Some(entry.as_str()))
+    {
+        *line = format!("    {entry}");
+    } else {
+        // Keep the list sorted, the entries look like `12 => day12,` with or without a comment.
+        let list_day = |line: &str| {
+            let line = line.trim().trim_start_matches("//").trim_start();
+            line.split_once(" => ")?.0.parse::<u8>().ok()
+        };
+        let end = lines
+            .iter()
+            .rposition(|line| line.trim() == ");")
+            .ok_or("Could not find the end of `aoc_year!`")?;
+        let position = lines
+            .iter()
+            .position(|line| list_day(line).is_some_and(|d| d > day))
+            .unwrap_or(end);
+        lines.insert(position, format!("    {entry}"));
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the module of `year` to the `aoc` binary that runs all years.
+fn register_year(main: &str, year: u16) -> Result<String, String> {
+    let module = format!("aoc{year}");
+    let mut lines = main.lines().map(String::from).collect::<Vec<_>>();
+
+    let last_module = lines
+        .iter()
+        .rposition(|line| line.starts_with("mod aoc"))
+        .ok_or("Could not find the year modules of the aoc binary")?;
+    lines.insert(last_module + 1, format!("#[path = \"../{module}/mod.rs\"]"));
+    lines.insert(last_module + 2, format!("mod {module};"));
+
+    let aoc_main = lines
+        .iter_mut()
+        .find(|line| line.starts_with("aoc_main!("))
+        .ok_or("Could not find `aoc_main!` in the aoc binary")?;
+    let years = aoc_main
+        .strip_prefix("aoc_main!(")
+        .and_then(|years| years.strip_suffix(");"))
+        .ok_or("Expected `aoc_main!` on a single line")?;
+    *aoc_main = format!("aoc_main!({years}, {module});");
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the `[[bin]]` entry of a year after the existing ones.
+fn add_bin(manifest: &str, year: u16) -> String {
+    let entry = format!("[[bin]]\nname = \"aoc{year}\"\ntest = false\nbench = false\n");
+    let last_bin = manifest
+        .match_indices("[[bin]]\nname = \"aoc2")
+        .last()
+        .map(|(index, _)| index);
+
+    // Insert before the next section that follows the last year, or at the end.
+    match last_bin.and_then(|last| Some(last + manifest[last..].find("\n\n")? + 2)) {
+        Some(end) => format!("{}{entry}\n{}", &manifest[..end], &manifest[end..]),
+        None => format!("{}\n\n{entry}", manifest.trim_end()),
+    }
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    let write = || {
+        std::fs::create_dir_all(path.parent().unwrap_or(path))?;
+        std::fs::write(path, content)
+    };
+    write().map_err(|e| format!("Could not write {}: {e}", path.display()))
+}
+
+fn update(path: &Path, f: impl FnOnce(&str) -> Result<String, String>) -> Result<(), String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+    let content = f(&content).map_err(|e| format!("{e} in {}", path.display()))?;
+    write(path, &content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_day() {
+        let year_mod = "aoc_year!(\n    2022;\n    1 => day1,\n    // 2 => day2,\n);\n";
+        assert_eq!(
+            register_day(year_mod, 2).unwrap(),
+            "aoc_year!(\n    2022;\n    1 => day1,\n    2 => day2,\n);\n"
+        );
+        assert_eq!(register_day(year_mod, 1).unwrap(), year_mod);
+
+        let year_mod = "aoc_year!(\n    2022;\n    1 => day1,\n    5 => day5,\n);\n";
+        assert_eq!(
+            register_day(year_mod, 3).unwrap(),
+            "aoc_year!(\n    2022;\n    1 => day1,\n    3 => day3,\n    5 => day5,\n);\n"
+        );
+        assert_eq!(
+            register_day(year_mod, 7).unwrap(),
+            "aoc_year!(\n    2022;\n    1 => day1,\n    5 => day5,\n    7 => day7,\n);\n"
+        );
+
+        let new_year = register_day(&year_module(2023), 1).unwrap();
+        assert!(new_year.starts_with("aoc_year!(\n    2023;\n    1 => day1,\n    // 2 => day2,\n"));
+    }
+
+    #[test]
+    fn test_register_year() {
+        let main = "#[path = \"../aoc2022/mod.rs\"]\nmod aoc2022;\n\naoc_main!(aoc2022);\n";
+        assert_eq!(
+            register_year(main, 2023).unwrap(),
+            "#[path = \"../aoc2022/mod.rs\"]\nmod aoc2022;\n#[path = \"../aoc2023/mod.rs\"]\nmod aoc2023;\n\naoc_main!(aoc2022, aoc2023);\n"
+        );
+    }
+
+    #[test]
+    fn test_add_bin() {
+        let manifest = "[[bin]]\nname = \"aoc\"\n\n[[bin]]\nname = \"aoc2022\"\ntest = false\nbench = false\n\n[profile.release]\nlto = true\n";
+        assert_eq!(
+            add_bin(manifest, 2023),
+            "[[bin]]\nname = \"aoc\"\n\n[[bin]]\nname = \"aoc2022\"\ntest = false\nbench = false\n\n[[bin]]\nname = \"aoc2023\"\ntest = false\nbench = false\n\n[profile.release]\nlto = true\n"
+        );
+    }
+}
@@ -0,0 +1,427 @@
+//! Breadth-first search, Dijkstra and A* over graphs that are given as a function from a node
+//! to its neighbors.
+//!
+//! A search reports every node that it reaches exactly once to a visitor, in the order of
+//! their cost, together with that cost. The visitor decides when to stop by returning
+//! [`ControlFlow::Break`], whose value becomes the result of the search:
+//!
+//! ```
+//! use std::ops::ControlFlow;
+//!
+//! let steps = aoc::search::bfs([1_u32], |&n| [n + 1, n * 2], |&n, steps| {
+//!     if n == 10 {
+//!         ControlFlow::Break(steps)
+//!     } else {
+//!         ControlFlow::Continue(())
+//!     }
+//! });
+//! assert_eq!(steps, Some(4));
+//! ```
+//!
+//! The free functions keep the visited nodes in a hash set. [`Search`] can use a [`Dense`] set
+//! instead, remembers the paths if asked to, and keeps both between searches.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::{Add, ControlFlow},
+};
+
+use fxhash::{FxHashMap, FxHashSet};
+
+/// The nodes that a search has already reached.
+pub trait Visited<N> {
+    /// Marks `node` as visited, `false` if it already was.
+    fn insert(&mut self, node: &N) -> bool;
+
+    fn contains(&self, node: &N) -> bool;
+}
+
+impl<N: Clone + Eq + Hash> Visited<N> for FxHashSet<N> {
+    fn insert(&mut self, node: &N) -> bool {
+        // Checking first avoids the clone for nodes that were already visited.
+        !self.contains(node) && Self::insert(self, node.clone())
+    }
+
+    fn contains(&self, node: &N) -> bool {
+        Self::contains(self, node)
+    }
+}
+
+/// A visited set for nodes that map to small indices, e.g. integers or the positions of a
+/// [`Grid`](crate::grid::Grid), which is a lot faster than hashing them.
+#[derive(Clone, Debug)]
+pub struct Dense<F> {
+    seen: Vec<bool>,
+    index: F,
+}
+
+impl<F> Dense<F> {
+    /// `index` maps every node to an index below `len`.
+    pub fn new<N>(len: usize, index: F) -> Self
+    where
+        F: Fn(&N) -> usize,
+    {
+        Self {
+            seen: vec![false; len],
+            index,
+        }
+    }
+}
+
+impl<N, F: Fn(&N) -> usize> Visited<N> for Dense<F> {
+    fn insert(&mut self, node: &N) -> bool {
+        let seen = &mut self.seen[(self.index)(node)];
+        !std::mem::replace(seen, true)
+    }
+
+    fn contains(&self, node: &N) -> bool {
+        self.seen[(self.index)(node)]
+    }
+}
+
+/// Same as [`Search::bfs`], with a hash set of the visited nodes.
+pub fn bfs<N, I, T>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    visit: impl FnMut(&N, usize) -> ControlFlow<T>,
+) -> Option<T>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    Search::new().bfs(starts, neighbors, visit)
+}
+
+/// Same as [`Search::dijkstra`], with a hash set of the visited nodes.
+pub fn dijkstra<N, C, I, T>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    visit: impl FnMut(&N, C) -> ControlFlow<T>,
+) -> Option<T>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    Search::new().dijkstra(starts, neighbors, visit)
+}
+
+/// Same as [`Search::astar`], with a hash set of the visited nodes.
+pub fn astar<N, C, I, T>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    visit: impl FnMut(&N, C) -> ControlFlow<T>,
+) -> Option<T>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    Search::new().astar(starts, neighbors, heuristic, visit)
+}
+
+/// The state of a search, which is kept between searches.
+#[derive(Clone, Debug)]
+pub struct Search<N, V = FxHashSet<N>> {
+    visited: V,
+    parents: Option<FxHashMap<N, N>>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_visited(FxHashSet::default())
+    }
+}
+
+impl<N: Clone + Eq + Hash> Default for Search<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, V> Search<N, V>
+where
+    N: Clone + Eq + Hash,
+    V: Visited<N>,
+{
+    pub const fn with_visited(visited: V) -> Self {
+        Self {
+            visited,
+            parents: None,
+        }
+    }
+
+    /// Remembers how every node was reached, for [`Search::path`].
+    #[must_use]
+    pub fn with_paths(self) -> Self {
+        Self {
+            parents: Some(FxHashMap::default()),
+            ..self
+        }
+    }
+
+    pub const fn visited(&self) -> &V {
+        &self.visited
+    }
+
+    /// Visits the nodes in the order of the number of steps from the closest start.
+    pub fn bfs<I, T>(
+        &mut self,
+        starts: impl IntoIterator<Item = N>,
+        mut neighbors: impl FnMut(&N) -> I,
+        mut visit: impl FnMut(&N, usize) -> ControlFlow<T>,
+    ) -> Option<T>
+    where
+        I: IntoIterator<Item = N>,
+    {
+        let mut queue = starts
+            .into_iter()
+            .filter(|start| self.visited.insert(start))
+            .map(|start| (start, 0))
+            .collect::<VecDeque<_>>();
+
+        while let Some((node, steps)) = queue.pop_front() {
+            if let ControlFlow::Break(res) = visit(&node, steps) {
+                return Some(res);
+            }
+
+            for next in neighbors(&node) {
+                if self.visited.insert(&next) {
+                    if let Some(parents) = &mut self.parents {
+                        parents.insert(next.clone(), node.clone());
+                    }
+                    queue.push_back((next, steps + 1));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Visits the nodes in the order of their cost from the closest start, where `neighbors`
+    /// returns every neighbor with the cost of the step to it.
+    pub fn dijkstra<C, I, T>(
+        &mut self,
+        starts: impl IntoIterator<Item = N>,
+        neighbors: impl FnMut(&N) -> I,
+        visit: impl FnMut(&N, C) -> ControlFlow<T>,
+    ) -> Option<T>
+    where
+        C: Copy + Ord + Add<Output = C> + Default,
+        I: IntoIterator<Item = (N, C)>,
+    {
+        self.astar(starts, neighbors, |_| C::default(), visit)
+    }
+
+    /// Same as [`Search::dijkstra`], but prefers the nodes that `heuristic` estimates to be
+    /// closer to the goal. The heuristic must never overestimate the remaining cost, and it
+    /// must not drop by more than the cost of a step, otherwise the costs can be too high.
+    pub fn astar<C, I, T>(
+        &mut self,
+        starts: impl IntoIterator<Item = N>,
+        mut neighbors: impl FnMut(&N) -> I,
+        mut heuristic: impl FnMut(&N) -> C,
+        mut visit: impl FnMut(&N, C) -> ControlFlow<T>,
+    ) -> Option<T>
+    where
+        C: Copy + Ord + Add<Output = C> + Default,
+        I: IntoIterator<Item = (N, C)>,
+    {
+        let mut queue = starts
+            .into_iter()
+            .map(|start| Entry {
+                estimate: heuristic(&start),
+                cost: C::default(),
+                node: start,
+                parent: None,
+            })
+            .collect::<BinaryHeap<_>>();
+
+        // A node can be queued more than once, only its cheapest entry is visited.
+        while let Some(Entry {
+            cost, node, parent, ..
+        }) = queue.pop()
+        {
+            if !self.visited.insert(&node) {
+                continue;
+            }
+            if let (Some(parents), Some(parent)) = (&mut self.parents, parent) {
+                parents.insert(node.clone(), parent);
+            }
+            if let ControlFlow::Break(res) = visit(&node, cost) {
+                return Some(res);
+            }
+
+            for (next, step) in neighbors(&node) {
+                if !self.visited.contains(&next) {
+                    let cost = cost + step;
+                    queue.push(Entry {
+                        estimate: cost + heuristic(&next),
+                        cost,
+                        node: next,
+                        parent: self.parents.is_some().then(|| node.clone()),
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    /// The path from a start to `to`, including both, if `to` was reached and the search
+    /// remembers the paths, see [`Search::with_paths`].
+    #[must_use]
+    pub fn path(&self, to: &N) -> Option<Vec<N>> {
+        let parents = self.parents.as_ref()?;
+        if !self.visited.contains(to) {
+            return None;
+        }
+
+        let mut path = vec![to.clone()];
+        let mut node = to;
+        while let Some(parent) = parents.get(node) {
+            path.push(parent.clone());
+            node = parent;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// A queued node of [`Search::astar`], the entry with the lowest estimate is the greatest.
+struct Entry<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+    parent: Option<N>,
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        grid::{Grid, Pos},
+        PuzzleInput,
+    };
+
+    const MAZE: &str = "
+        ..#....
+        .##.##.
+        ....#..
+        ##.####
+        ....#..
+    ";
+
+    fn open(grid: &Grid<u8>) -> impl FnMut(&Pos) -> Vec<Pos> + '_ {
+        |&pos| {
+            grid.neighbors4(pos)
+                .filter(|&next| grid[next] == b'.')
+                .collect()
+        }
+    }
+
+    fn find<N: PartialEq, C>(goal: N) -> impl FnMut(&N, C) -> ControlFlow<C> {
+        move |node, cost| {
+            if *node == goal {
+                ControlFlow::Break(cost)
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = Grid::<u8>::from_input(MAZE);
+        assert_eq!(bfs([(0, 0)], open(&grid), find((6, 0))), Some(10));
+        assert_eq!(bfs([(0, 0)], open(&grid), find((6, 4))), None);
+        assert_eq!(bfs([(0, 0), (6, 2)], open(&grid), find((6, 0))), Some(2));
+
+        let mut seen = Vec::new();
+        let none = bfs([(0, 0)], open(&grid), |&pos, _| {
+            seen.push(pos);
+            ControlFlow::<()>::Continue(())
+        });
+        assert_eq!(none, None);
+        assert_eq!(seen.len(), 20);
+        assert_eq!(seen[..3], [(0, 0), (1, 0), (0, 1)]);
+    }
+
+    #[test]
+    fn test_paths() {
+        let grid = Grid::<u8>::from_input(MAZE);
+        let visited = Dense::new(grid.len(), |&pos| grid.index_of(pos));
+        let mut search = Search::with_visited(visited).with_paths();
+        assert_eq!(search.bfs([(0, 0)], open(&grid), find((3, 1))), Some(6));
+
+        let path = search.path(&(3, 1)).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path[0], (0, 0));
+        assert_eq!(path[6], (3, 1));
+        assert!(path
+            .windows(2)
+            .all(|w| grid.neighbors4(w[0]).any(|n| n == w[1])));
+        assert_eq!(search.path(&(6, 4)), None);
+        assert_eq!(Search::new().path(&(0, 0)), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // Going right costs the digit of the next cell, going down is free.
+        let grid = Grid::<u8>::from_input("191\n119\n911\n");
+        let neighbors = |&(x, y): &Pos| {
+            let right = grid.step((x, y), (1, 0)).map(|n| (n, grid[n] - b'0'));
+            let down = grid.step((x, y), (0, 1)).map(|n| (n, 0));
+            right.into_iter().chain(down)
+        };
+        assert_eq!(dijkstra([(0, 0)], neighbors, find((2, 2))), Some(2));
+        assert_eq!(dijkstra([(0, 0)], neighbors, find((2, 0))), Some(10));
+
+        // Every step to the right costs at least 1.
+        let steps_right = |&(x, _): &Pos| 2 - x as u8;
+        let mut search = Search::new().with_paths();
+        let cost = search.astar([(0, 0)], neighbors, steps_right, find((2, 2)));
+        assert_eq!(cost, Some(2));
+        assert_eq!(search.path(&(2, 2)).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid = Grid::<u8>::from_input(MAZE);
+        let weighted = |&pos: &Pos| open(&grid)(&pos).into_iter().map(|next| (next, 1));
+        let manhattan = |&(x, y): &Pos| 6 - x + y;
+
+        let mut expanded = 0;
+        let cost = astar([(0, 0)], weighted, manhattan, |&pos, cost| {
+            expanded += 1;
+            find((6, 0))(&pos, cost)
+        });
+        assert_eq!(cost, Some(10));
+        assert!(expanded < 20, "{expanded}");
+        assert_eq!(dijkstra([(0, 0)], weighted, find((6, 0))), Some(10));
+    }
+}